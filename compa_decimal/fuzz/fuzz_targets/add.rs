@@ -0,0 +1,21 @@
+#![no_main]
+
+use compa_decimal_fuzz::{oracle, parse_operand, split_operands, value};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((a, b)) = split_operands(data) else {
+        return;
+    };
+    let Some((compa, a_value)) = parse_operand(a) else {
+        return;
+    };
+    let result = compa.add(b);
+    match oracle(b) {
+        Some(b_value) => {
+            let sum = result.expect("adding valid digits cannot fail");
+            assert_eq!(value(&sum), a_value + b_value);
+        }
+        None => assert!(result.is_err()),
+    }
+});
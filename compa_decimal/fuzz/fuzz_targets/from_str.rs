@@ -0,0 +1,20 @@
+#![no_main]
+
+use compa_decimal::CompaDecimal;
+use compa_decimal_fuzz::{oracle, value};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let parsed = input.parse::<CompaDecimal>();
+    match oracle(input) {
+        Some(expected) => {
+            let compa = parsed.expect("every string of compa digits has to parse");
+            assert_eq!(value(&compa), expected);
+            assert_eq!(compa.get_value(), input);
+        }
+        None => assert!(parsed.is_err()),
+    }
+});
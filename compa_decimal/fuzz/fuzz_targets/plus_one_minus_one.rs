@@ -0,0 +1,24 @@
+#![no_main]
+
+use compa_decimal_fuzz::{parse_operand, value};
+use libfuzzer_sys::fuzz_target;
+use num_bigint::BigUint;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Some((compa, expected)) = parse_operand(input) else {
+        return;
+    };
+
+    let next = compa.plus_one().expect("incrementing cannot fail");
+    assert_eq!(value(&next), &expected + 1u8);
+    let back = next.minus_one().expect("the value is at least one");
+    assert_eq!(value(&back), expected);
+
+    match compa.minus_one() {
+        Ok(previous) => assert_eq!(value(&previous) + 1u8, expected),
+        Err(_) => assert_eq!(expected, BigUint::from(0u8)),
+    }
+});
@@ -0,0 +1,30 @@
+#![no_main]
+
+use compa_decimal_fuzz::{oracle, parse_operand, split_operands, value};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((a, b)) = split_operands(data) else {
+        return;
+    };
+    let Some((compa, a_value)) = parse_operand(a) else {
+        return;
+    };
+    let result = compa.subtract(b);
+    let ordering = compa.cmp_str(b);
+    match oracle(b) {
+        Some(b_value) => {
+            assert_eq!(ordering.unwrap(), a_value.cmp(&b_value));
+            if a_value >= b_value {
+                let difference = result.expect("the difference is not negative");
+                assert_eq!(value(&difference), a_value - b_value);
+            } else {
+                assert!(result.is_err());
+            }
+        }
+        None => {
+            assert!(result.is_err());
+            assert!(ordering.is_err());
+        }
+    }
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+use compa_decimal_fuzz::parse_operand;
+use libfuzzer_sys::fuzz_target;
+use num_bigint::BigUint;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Some((compa, expected)) = parse_operand(input) else {
+        return;
+    };
+    match compa.to_decimal::<u128>() {
+        Ok(num) => assert_eq!(BigUint::from(num), expected),
+        Err(_) => assert!(expected > BigUint::from(u128::MAX)),
+    }
+});
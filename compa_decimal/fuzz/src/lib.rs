@@ -0,0 +1,36 @@
+use compa_decimal::{CompaDecimal, COMPA_DIGITS};
+use num_bigint::BigUint;
+
+// Reference decoding that only uses the alphabet, so a bug in the crate's own
+// parsing or arithmetic cannot hide itself in the expected value. Like the crate, it
+// treats an empty string as no number at all.
+pub fn oracle(input: &str) -> Option<BigUint> {
+    if input.is_empty() {
+        return None;
+    }
+    let mut num = BigUint::from(0u8);
+    for ch in input.chars() {
+        let index = COMPA_DIGITS.iter().position(|&digit| digit as char == ch)?;
+        num = num * COMPA_DIGITS.len() + index;
+    }
+    Some(num)
+}
+
+// Binary targets take two operands separated by a newline, which is not a compa digit.
+pub fn split_operands(data: &[u8]) -> Option<(&str, &str)> {
+    std::str::from_utf8(data).ok()?.split_once('\n')
+}
+
+pub fn parse_operand(input: &str) -> Option<(CompaDecimal, BigUint)> {
+    let expected = oracle(input)?;
+    let compa = input
+        .parse()
+        .expect("every string of compa digits has to parse");
+    Some((compa, expected))
+}
+
+pub fn value(compa: &CompaDecimal) -> BigUint {
+    compa
+        .to_biguint()
+        .expect("a CompaDecimal only holds valid compa digits")
+}
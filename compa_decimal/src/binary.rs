@@ -0,0 +1,218 @@
+use std::io::{self, Read, Write};
+
+use num::Zero;
+use num_bigint::BigUint;
+
+use crate::{
+    compadecimal::CompaDecimal,
+    error::CompaDecimalError,
+    ops::{from_biguint, to_biguint},
+};
+
+// A u64 length needs at most ten 7-bit groups.
+const MAX_VARINT_BYTES: usize = 10;
+// The magnitude is read in chunks, so a corrupt length cannot force a huge allocation.
+const READ_CHUNK: usize = 4096;
+
+impl CompaDecimal {
+    // The binary form is an unsigned LEB128 length followed by that many big-endian
+    // magnitude bytes. Zero has an empty magnitude and no magnitude starts with 0x00,
+    // so every value has exactly one encoding.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let magnitude = magnitude_bytes(self);
+        let mut bytes = Vec::with_capacity(MAX_VARINT_BYTES + magnitude.len());
+        let mut len = magnitude.len() as u64;
+        while len >= 0x80 {
+            bytes.push((len as u8 & 0x7f) | 0x80);
+            len >>= 7;
+        }
+        bytes.push(len as u8);
+        bytes.extend_from_slice(&magnitude);
+        bytes
+    }
+
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<CompaDecimal, CompaDecimalError> {
+        let mut rest = bytes;
+        let compa = CompaDecimal::read_from(&mut rest)?;
+        if !rest.is_empty() {
+            return Err(CompaDecimalError {
+                error_message: format!("{} trailing bytes after a compa value", rest.len()),
+            });
+        }
+        Ok(compa)
+    }
+
+    pub fn read_from(reader: &mut impl Read) -> Result<CompaDecimal, CompaDecimalError> {
+        decode_with(
+            |buf| reader.read_exact(buf).map_err(io_error),
+            |error_message| CompaDecimalError { error_message },
+        )
+    }
+}
+
+fn magnitude_bytes(compa: &CompaDecimal) -> Vec<u8> {
+    let num = to_biguint(compa);
+    if num.is_zero() {
+        Vec::new()
+    } else {
+        num.to_bytes_be()
+    }
+}
+
+fn from_magnitude(magnitude: &[u8]) -> Result<CompaDecimal, String> {
+    if magnitude.first() == Some(&0) {
+        return Err("Magnitude bytes cannot start with a zero byte".to_string());
+    }
+    Ok(from_biguint(&BigUint::from_bytes_be(magnitude)))
+}
+
+fn io_error(error: io::Error) -> CompaDecimalError {
+    let error_message = match error.kind() {
+        io::ErrorKind::UnexpectedEof => "Unexpected end of input in a compa value".to_string(),
+        _ => format!("Failed to read a compa value: {}", error),
+    };
+    CompaDecimalError { error_message }
+}
+
+// Shared by `read_from` and the bincode decoder, which only differ in how bytes are
+// pulled and how errors are wrapped.
+fn decode_with<E>(
+    mut read_exact: impl FnMut(&mut [u8]) -> Result<(), E>,
+    invalid: impl Fn(String) -> E,
+) -> Result<CompaDecimal, E> {
+    let mut len = 0u64;
+    let mut shift = 0;
+    loop {
+        let mut byte = [0u8];
+        read_exact(&mut byte)?;
+        let byte = byte[0];
+        if shift == 7 * (MAX_VARINT_BYTES - 1) && byte > 1 {
+            return Err(invalid("Length prefix overflows a u64".to_string()));
+        }
+        len |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            if byte == 0 && shift > 0 {
+                return Err(invalid(
+                    "Length prefix is not minimally encoded".to_string(),
+                ));
+            }
+            break;
+        }
+        shift += 7;
+    }
+
+    let len = usize::try_from(len)
+        .map_err(|_| invalid(format!("Length {} does not fit in memory", len)))?;
+    let mut magnitude = Vec::with_capacity(len.min(READ_CHUNK));
+    let mut chunk = [0u8; READ_CHUNK];
+    while magnitude.len() < len {
+        let n = (len - magnitude.len()).min(READ_CHUNK);
+        read_exact(&mut chunk[..n])?;
+        magnitude.extend_from_slice(&chunk[..n]);
+    }
+    from_magnitude(&magnitude).map_err(invalid)
+}
+
+#[cfg(feature = "bincode")]
+impl bincode::Encode for CompaDecimal {
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        use bincode::enc::write::Writer;
+        encoder.writer().write(&self.to_bytes())
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<Context> bincode::Decode<Context> for CompaDecimal {
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        use bincode::de::read::Reader;
+        decode_with(
+            |buf| {
+                decoder.claim_bytes_read(buf.len())?;
+                decoder.reader().read(buf)
+            },
+            bincode::error::DecodeError::OtherString,
+        )
+    }
+}
+
+#[cfg(feature = "bincode")]
+bincode::impl_borrow_decode!(CompaDecimal);
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for CompaDecimal {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.write_to(writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for CompaDecimal {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        CompaDecimal::read_from(reader)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error.error_message))
+    }
+}
+
+// Human-readable formats get the compa string. Binary formats such as postcard get the
+// magnitude bytes, since those formats already length-prefix a byte string.
+#[cfg(feature = "serde")]
+impl serde::Serialize for CompaDecimal {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.get_value())
+        } else {
+            serializer.serialize_bytes(&magnitude_bytes(self))
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CompaDecimal {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CompaVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for CompaVisitor {
+            type Value = CompaDecimal;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a compa string or big-endian magnitude bytes")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<CompaDecimal, E> {
+                value
+                    .parse()
+                    .map_err(|error: CompaDecimalError| E::custom(error.error_message))
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, value: &[u8]) -> Result<CompaDecimal, E> {
+                from_magnitude(value).map_err(E::custom)
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<CompaDecimal, A::Error> {
+                let mut magnitude = Vec::new();
+                while let Some(byte) = seq.next_element::<u8>()? {
+                    magnitude.push(byte);
+                }
+                from_magnitude(&magnitude).map_err(serde::de::Error::custom)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(CompaVisitor)
+        } else {
+            deserializer.deserialize_bytes(CompaVisitor)
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use num::{PrimInt, Unsigned, Zero};
+use num_bigint::BigUint;
+
+use crate::{
+    compadecimal::CompaDecimal, error::CompaDecimalError, parse::zero_length,
+    utils::get_compa_digits,
+};
+
+impl CompaDecimal {
+    // No value is written with zero digits, so a length of zero is an error, the same
+    // as in `CompaUniform::with_len` and `parse_prefix_max`.
+    pub fn max_with_len(len: usize) -> Result<CompaDecimal, CompaDecimalError> {
+        if len == 0 {
+            return Err(zero_length());
+        }
+        let compa_digits = get_compa_digits();
+        let max_digit = compa_digits[compa_digits.len() - 1];
+        Ok(CompaDecimal::from_valid_string(
+            std::iter::repeat_n(max_digit, len).collect(),
+        ))
+    }
+
+    // Zero is the smallest value with one digit.
+    pub fn min_with_len(len: usize) -> Result<CompaDecimal, CompaDecimalError> {
+        match len {
+            0 => Err(zero_length()),
+            1 => Ok(CompaDecimal::new()),
+            _ => {
+                let compa_digits = get_compa_digits();
+                let mut value = String::with_capacity(len);
+                value.push(compa_digits[1]);
+                value.extend(std::iter::repeat_n(compa_digits[0], len - 1));
+                Ok(CompaDecimal::from_valid_string(value))
+            }
+        }
+    }
+
+    pub fn digits_needed<T>() -> usize
+    where
+        T: PrimInt + Unsigned,
+    {
+        let base = T::from(get_compa_digits().len()).expect("u8 can hold the compa base");
+        let mut num = T::max_value();
+        let mut len = 1;
+        while num >= base {
+            num = num / base;
+            len += 1;
+        }
+        len
+    }
+
+    pub fn digits_needed_for(num: &BigUint) -> usize {
+        if num.is_zero() {
+            return 1;
+        }
+        let base = BigUint::from(get_compa_digits().len());
+        let mut num = num.clone();
+        let mut len = 0;
+        while !num.is_zero() {
+            num /= &base;
+            len += 1;
+        }
+        len
+    }
+
+    // Compa digits per decimal digit for large values, log(10) / log(base).
+    pub fn compression_ratio_vs_decimal() -> f64 {
+        10f64.ln() / (get_compa_digits().len() as f64).ln()
+    }
+}
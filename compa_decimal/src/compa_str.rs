@@ -0,0 +1,159 @@
+use std::{borrow::Borrow, cmp::Ordering, fmt::Display, ops::Deref};
+
+use crate::{
+    compadecimal::{fmt_compa, CompaDecimal},
+    error::CompaDecimalError,
+    parse::check_digits,
+    utils::digit_index,
+};
+
+// A borrowed run of compa digits, to `CompaDecimal` what `str` is to `String`. The
+// digits are checked once in `new`, so arithmetic taking a `&CompaStr` can skip the
+// per-call validation the `&str` methods have to do. Like every other constructor,
+// `new` rejects an empty string.
+#[derive(Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct CompaStr(str);
+
+impl CompaStr {
+    pub fn new(value: &str) -> Result<&CompaStr, CompaDecimalError> {
+        check_digits(value.as_bytes())?;
+        Ok(CompaStr::from_valid_str(value))
+    }
+
+    pub(crate) fn from_valid_str(value: &str) -> &CompaStr {
+        // SAFETY: CompaStr is a repr(transparent) wrapper around str, so both
+        // references have the same layout and metadata.
+        unsafe { &*(value as *const str as *const CompaStr) }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    // The same digits without leading zeros, or "0" when nothing else is left.
+    pub fn normalized(&self) -> &CompaStr {
+        let trimmed = self.0.trim_start_matches('0');
+        if trimmed.is_empty() {
+            CompaStr::from_valid_str("0")
+        } else {
+            CompaStr::from_valid_str(trimmed)
+        }
+    }
+
+    // Compares by value, so "0005" and "5" are equal. `Ord` compares digit for digit
+    // instead, to agree with `Eq`.
+    pub fn cmp_value(&self, other: &CompaStr) -> Ordering {
+        self.normalized().cmp(other.normalized())
+    }
+
+    pub(crate) fn digit_indexes(&self) -> impl DoubleEndedIterator<Item = u8> + '_ {
+        self.0
+            .bytes()
+            .map(|byte| digit_index(byte).expect("a CompaStr only holds compa digits"))
+    }
+}
+
+impl Ord for CompaStr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.len()
+            .cmp(&other.len())
+            .then_with(|| self.digit_indexes().cmp(other.digit_indexes()))
+    }
+}
+
+impl PartialOrd for CompaStr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> TryFrom<&'a str> for &'a CompaStr {
+    type Error = CompaDecimalError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        CompaStr::new(value)
+    }
+}
+
+impl Display for CompaStr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_compa(&self.0, f)
+    }
+}
+
+impl AsRef<str> for CompaStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<CompaStr> for CompaStr {
+    fn as_ref(&self) -> &CompaStr {
+        self
+    }
+}
+
+impl PartialEq<&str> for CompaStr {
+    fn eq(&self, other: &&str) -> bool {
+        &self.0 == *other
+    }
+}
+
+impl PartialEq<CompaStr> for CompaDecimal {
+    fn eq(&self, other: &CompaStr) -> bool {
+        **self == *other
+    }
+}
+
+impl PartialEq<CompaDecimal> for CompaStr {
+    fn eq(&self, other: &CompaDecimal) -> bool {
+        *self == **other
+    }
+}
+
+impl ToOwned for CompaStr {
+    type Owned = CompaDecimal;
+
+    fn to_owned(&self) -> CompaDecimal {
+        CompaDecimal::from_valid_string(self.0.to_string())
+    }
+}
+
+impl Deref for CompaDecimal {
+    type Target = CompaStr;
+
+    fn deref(&self) -> &CompaStr {
+        CompaStr::from_valid_str(self.get_value())
+    }
+}
+
+impl Borrow<CompaStr> for CompaDecimal {
+    fn borrow(&self) -> &CompaStr {
+        self
+    }
+}
+
+impl AsRef<CompaStr> for CompaDecimal {
+    fn as_ref(&self) -> &CompaStr {
+        self
+    }
+}
+
+impl From<&CompaStr> for CompaDecimal {
+    fn from(value: &CompaStr) -> CompaDecimal {
+        value.to_owned()
+    }
+}
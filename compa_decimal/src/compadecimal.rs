@@ -1,40 +1,58 @@
 use num::{PrimInt, ToPrimitive, Unsigned, Zero};
 use num_bigint::BigUint;
-use std::{any::type_name_of_val, fmt::Display, str::FromStr};
+use std::{
+    any::type_name_of_val,
+    fmt::{Display, Write},
+    str::FromStr,
+};
 
-use crate::{error::*, utils::*};
+use crate::{compa_str::CompaStr, error::*, parse::check_digits, utils::*};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CompaDecimal {
     value: String,
 }
 
+// Orders digit for digit with the shorter value first, not by numeric value, so that
+// it agrees with the derived `Eq`. Leading zeros count, which puts "01" above "2".
+// `cmp_str` and `cmp_value` compare numerically.
 impl Ord for CompaDecimal {
+    // Shares `CompaStr`'s ordering, which `Borrow<CompaStr>` requires.
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let compa_digits = get_compa_digits();
-        if self.value.len() != other.value.len() {
-            return self.value.len().cmp(&other.value.len());
-        }
-        for (ac, bc) in self.value.chars().zip(other.value.chars()) {
-            let ai = compa_digits.iter().position(|&x| x == ac).unwrap();
-            let bi = compa_digits.iter().position(|&x| x == bc).unwrap();
-            if ai != bi {
-                return ai.cmp(&bi);
-            }
-        }
-        std::cmp::Ordering::Equal
+        (**self).cmp(&**other)
     }
 }
 
 impl PartialOrd for CompaDecimal {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.value.cmp(&other.value))
+        Some(self.cmp(other))
+    }
+}
+
+pub const ALTERNATE_PREFIX: &str = "0c";
+
+// `{:#}` adds ALTERNATE_PREFIX and `{:0N}` pads with the alphabet's zero digit
+// after the prefix; everything else goes through `Formatter::pad`.
+pub(crate) fn fmt_compa(digits: &str, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let prefix = if f.alternate() { ALTERNATE_PREFIX } else { "" };
+    if let (true, Some(width)) = (f.sign_aware_zero_pad(), f.width()) {
+        f.write_str(prefix)?;
+        let zero_digit = COMPA_DIGITS[0] as char;
+        for _ in (prefix.len() + digits.len())..width {
+            f.write_char(zero_digit)?;
+        }
+        return f.write_str(digits);
+    }
+    if prefix.is_empty() {
+        f.pad(digits)
+    } else {
+        f.pad(&format!("{}{}", prefix, digits))
     }
 }
 
 impl Display for CompaDecimal {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value)
+        fmt_compa(&self.value, f)
     }
 }
 
@@ -50,11 +68,7 @@ impl TryFrom<&str> for CompaDecimal {
     type Error = CompaDecimalError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if !valid_str(value) {
-            return Err(CompaDecimalError {
-                error_message: "All chars have to be valid compa digits".to_string(),
-            });
-        }
+        check_digits(value.as_bytes())?;
         Ok(CompaDecimal {
             value: value.to_string(),
         })
@@ -65,11 +79,7 @@ impl FromStr for CompaDecimal {
     type Err = CompaDecimalError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if !valid_str(s) {
-            return Err(CompaDecimalError {
-                error_message: "All chars have to be valid compa digits".to_string(),
-            });
-        }
+        check_digits(s.as_bytes())?;
         Ok(CompaDecimal {
             value: s.to_string(),
         })
@@ -93,6 +103,20 @@ impl CompaDecimal {
         &self.value
     }
 
+    pub(crate) fn from_valid_string(value: String) -> CompaDecimal {
+        CompaDecimal { value }
+    }
+
+    fn from_digit_bytes(digits: Vec<u8>) -> CompaDecimal {
+        CompaDecimal {
+            value: String::from_utf8(digits).expect("compa digits are ASCII"),
+        }
+    }
+
+    pub(crate) fn normalized_value(&self) -> &str {
+        self.normalized().as_str()
+    }
+
     pub fn decimal_to_compa<T>(mut num: T) -> Result<CompaDecimal, CompaDecimalError>
     where
         T: PrimInt + Unsigned,
@@ -208,6 +232,10 @@ impl CompaDecimal {
         self.value.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
     pub fn plus_one(&self) -> Result<CompaDecimal, CompaDecimalError> {
         let compa_digits = get_compa_digits();
         let base = compa_digits.len();
@@ -302,117 +330,74 @@ impl CompaDecimal {
     }
 
     pub fn add(&self, additional_value: &str) -> Result<CompaDecimal, CompaDecimalError> {
-        if !valid_str(additional_value) {
-            return Err(CompaDecimalError {
-                error_message: "All chars have to be valid compa digits".to_string(),
-            });
-        }
-        let compa_digits = get_compa_digits();
-        let base = compa_digits.len();
-
-        let mut a: Vec<char> = self.value.chars().collect();
-        let mut b: Vec<char> = additional_value.chars().collect();
+        Ok(self.add_compa(CompaStr::new(additional_value)?))
+    }
 
-        while a.len() < b.len() {
-            a.insert(0, '0');
-        }
-        while b.len() < a.len() {
-            b.insert(0, '0');
-        }
+    pub fn add_compa(&self, additional_value: &CompaStr) -> CompaDecimal {
+        let base = COMPA_DIGITS.len();
+        let width = self.len().max(additional_value.len());
+        let mut a = self.digit_indexes().rev();
+        let mut b = additional_value.digit_indexes().rev();
 
         let mut carry = 0;
-        let mut result = Vec::with_capacity(a.len() + 1);
+        let mut result = Vec::with_capacity(width + 1);
 
-        for i in (0..a.len()).rev() {
-            let ai = compa_digits.iter().position(|&x| x == a[i]).unwrap();
-            let bi = compa_digits.iter().position(|&x| x == b[i]).unwrap();
-            let sum = ai + bi + carry;
-            result.push(compa_digits[sum % base]);
+        for _ in 0..width {
+            let sum = a.next().unwrap_or(0) as usize + b.next().unwrap_or(0) as usize + carry;
+            result.push(COMPA_DIGITS[sum % base]);
             carry = sum / base;
         }
 
         if carry > 0 {
-            result.push(compa_digits[carry]);
+            result.push(COMPA_DIGITS[carry]);
         }
 
         result.reverse();
-        Ok(CompaDecimal {
-            value: result.into_iter().collect(),
-        })
+        CompaDecimal::from_digit_bytes(result)
     }
 
     pub fn subtract(&self, subtrahend: &str) -> Result<CompaDecimal, CompaDecimalError> {
-        if !valid_str(subtrahend) {
+        self.subtract_compa(CompaStr::new(subtrahend)?)
+    }
+
+    pub fn subtract_compa(&self, subtrahend: &CompaStr) -> Result<CompaDecimal, CompaDecimalError> {
+        if self.cmp_value(subtrahend) == std::cmp::Ordering::Less {
             return Err(CompaDecimalError {
-                error_message: "All chars have to be valid compa digits".to_string(),
+                error_message: "Result would be negative".to_string(),
             });
         }
-        let compa_digits = get_compa_digits();
-        let base = compa_digits.len();
-        match self.cmp_str(subtrahend) {
-            Ok(cmp_result) => {
-                if cmp_result == std::cmp::Ordering::Less {
-                    return Err(CompaDecimalError {
-                        error_message: "Result would be negative".to_string(),
-                    });
-                }
-            }
-            Err(error) => return Err(error),
-        }
-
-        let mut a: Vec<char> = self.value.chars().collect();
-        let mut b: Vec<char> = subtrahend.chars().collect();
+        let base = COMPA_DIGITS.len() as isize;
+        let width = self.len().max(subtrahend.len());
+        let mut a = self.digit_indexes().rev();
+        let mut b = subtrahend.digit_indexes().rev();
 
-        while a.len() < b.len() {
-            a.insert(0, '0');
-        }
-        while b.len() < a.len() {
-            b.insert(0, '0');
-        }
-
-        let mut result = Vec::with_capacity(a.len());
+        let mut result = Vec::with_capacity(width);
         let mut borrow = 0;
 
-        for i in (0..a.len()).rev() {
-            let ai = compa_digits.iter().position(|&x| x == a[i]).unwrap() as isize;
-            let bi = compa_digits.iter().position(|&x| x == b[i]).unwrap() as isize;
+        for _ in 0..width {
+            let ai = a.next().unwrap_or(0) as isize;
+            let bi = b.next().unwrap_or(0) as isize;
             let mut diff = ai - bi - borrow;
             if diff < 0 {
-                diff += base as isize;
+                diff += base;
                 borrow = 1;
             } else {
                 borrow = 0;
             }
-            result.push(compa_digits[diff as usize]);
+            result.push(COMPA_DIGITS[diff as usize]);
         }
 
-        while result.len() > 1 && result.last() == Some(&'0') {
+        while result.len() > 1 && result.last() == Some(&b'0') {
             result.pop();
         }
 
         result.reverse();
-        Ok(CompaDecimal {
-            value: result.into_iter().collect(),
-        })
+        Ok(CompaDecimal::from_digit_bytes(result))
     }
 
     pub fn cmp_str(&self, comparand: &str) -> Result<std::cmp::Ordering, CompaDecimalError> {
-        if !valid_str(comparand) {
-            return Err(CompaDecimalError {
-                error_message: "All chars have to be valid compa digits".to_string(),
-            });
-        }
-        let compa_digits = get_compa_digits();
-        if self.value.len() != comparand.len() {
-            return Ok(self.value.len().cmp(&comparand.len()));
-        }
-        for (ac, bc) in self.value.chars().zip(comparand.chars()) {
-            let ai = compa_digits.iter().position(|&x| x == ac).unwrap();
-            let bi = compa_digits.iter().position(|&x| x == bc).unwrap();
-            if ai != bi {
-                return Ok(ai.cmp(&bi));
-            }
-        }
-        Ok(std::cmp::Ordering::Equal)
+        // Numeric, unlike `Ord`: `cmp_value` drops leading zeros before comparing
+        // lengths, so "0005" is less than "6".
+        Ok(self.cmp_value(CompaStr::new(comparand)?))
     }
 }
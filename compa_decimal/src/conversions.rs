@@ -0,0 +1,91 @@
+use num_bigint::BigUint;
+
+use crate::{
+    compadecimal::CompaDecimal,
+    error::CompaDecimalError,
+    ops::{from_biguint, to_biguint},
+};
+
+macro_rules! impl_unsigned_conversions {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for CompaDecimal {
+                fn from(num: $t) -> Self {
+                    CompaDecimal::decimal_to_compa::<$t>(num)
+                        .expect("an unsigned integer always converts to a compa value")
+                }
+            }
+
+            impl TryFrom<&CompaDecimal> for $t {
+                type Error = CompaDecimalError;
+
+                fn try_from(compa: &CompaDecimal) -> Result<Self, Self::Error> {
+                    compa.to_decimal::<$t>()
+                }
+            }
+
+            impl TryFrom<CompaDecimal> for $t {
+                type Error = CompaDecimalError;
+
+                fn try_from(compa: CompaDecimal) -> Result<Self, Self::Error> {
+                    compa.to_decimal::<$t>()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed_conversions {
+    ($($t:ty => $unsigned:ty),*) => {
+        $(
+            impl TryFrom<&CompaDecimal> for $t {
+                type Error = CompaDecimalError;
+
+                fn try_from(compa: &CompaDecimal) -> Result<Self, Self::Error> {
+                    let num = compa.to_decimal::<$unsigned>()?;
+                    <$t>::try_from(num).map_err(|_| CompaDecimalError {
+                        error_message: format!(
+                            "Overflow error! The compa value was too big to store in a {} data type",
+                            stringify!($t)
+                        ),
+                    })
+                }
+            }
+
+            impl TryFrom<CompaDecimal> for $t {
+                type Error = CompaDecimalError;
+
+                fn try_from(compa: CompaDecimal) -> Result<Self, Self::Error> {
+                    <$t>::try_from(&compa)
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned_conversions!(u8, u16, u32, u64, u128, usize);
+impl_signed_conversions!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+impl From<BigUint> for CompaDecimal {
+    fn from(num: BigUint) -> Self {
+        from_biguint(&num)
+    }
+}
+
+impl From<&BigUint> for CompaDecimal {
+    fn from(num: &BigUint) -> Self {
+        from_biguint(num)
+    }
+}
+
+impl From<CompaDecimal> for BigUint {
+    fn from(compa: CompaDecimal) -> Self {
+        to_biguint(&compa)
+    }
+}
+
+impl From<&CompaDecimal> for BigUint {
+    fn from(compa: &CompaDecimal) -> Self {
+        to_biguint(compa)
+    }
+}
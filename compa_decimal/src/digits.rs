@@ -0,0 +1,92 @@
+use crate::{
+    compadecimal::CompaDecimal,
+    error::CompaDecimalError,
+    utils::{digit_char, digit_value, COMPA_DIGITS},
+};
+
+fn from_digits(digits: &str) -> CompaDecimal {
+    let trimmed = digits.trim_start_matches('0');
+    if trimmed.is_empty() {
+        CompaDecimal::new()
+    } else {
+        CompaDecimal::from_valid_string(trimmed.to_string())
+    }
+}
+
+impl CompaDecimal {
+    pub fn shl_digits(&self, k: usize) -> CompaDecimal {
+        let digits = self.normalized_value();
+        if digits == "0" {
+            return CompaDecimal::new();
+        }
+        let mut value = String::with_capacity(digits.len() + k);
+        value.push_str(digits);
+        value.extend(std::iter::repeat_n('0', k));
+        CompaDecimal::from_valid_string(value)
+    }
+
+    pub fn shr_digits(&self, k: usize) -> CompaDecimal {
+        let digits = self.normalized_value();
+        if k >= digits.len() {
+            return CompaDecimal::new();
+        }
+        from_digits(&digits[..digits.len() - k])
+    }
+
+    pub fn low_digits(&self, k: usize) -> CompaDecimal {
+        let digits = self.normalized_value();
+        from_digits(&digits[digits.len().saturating_sub(k)..])
+    }
+
+    pub fn high_digits(&self, k: usize) -> CompaDecimal {
+        let digits = self.normalized_value();
+        if k == 0 {
+            return CompaDecimal::new();
+        }
+        from_digits(&digits[..k.min(digits.len())])
+    }
+
+    // Splits `k` digits from the least significant end, so that
+    // `high.shl_digits(k) + low == self`.
+    pub fn split_at_digit(&self, k: usize) -> (CompaDecimal, CompaDecimal) {
+        (self.shr_digits(k), self.low_digits(k))
+    }
+
+    pub fn digits(&self) -> impl DoubleEndedIterator<Item = u8> + '_ {
+        self.normalized_value()
+            .chars()
+            .map(|ch| digit_value(ch).expect("a CompaDecimal only holds valid compa digits"))
+    }
+
+    pub fn digit_count(&self) -> usize {
+        self.normalized_value().len()
+    }
+
+    // Position 0 is the least significant digit, so digit `i` has weight 95^i.
+    pub fn digit(&self, i: usize) -> Option<u8> {
+        self.digits().rev().nth(i)
+    }
+
+    // Position 0 is the most significant digit.
+    pub fn digit_from_most(&self, i: usize) -> Option<u8> {
+        self.digits().nth(i)
+    }
+
+    pub fn set_digit(&mut self, i: usize, value: u8) -> Result<(), CompaDecimalError> {
+        let ch = digit_char(value).ok_or_else(|| CompaDecimalError {
+            error_message: format!(
+                "Digit value has to be below {}, got {}",
+                COMPA_DIGITS.len(),
+                value
+            ),
+        })?;
+        let mut digits: Vec<char> = self.normalized_value().chars().collect();
+        while digits.len() <= i {
+            digits.insert(0, '0');
+        }
+        let index = digits.len() - 1 - i;
+        digits[index] = ch;
+        *self = from_digits(&digits.into_iter().collect::<String>());
+        Ok(())
+    }
+}
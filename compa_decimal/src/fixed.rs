@@ -0,0 +1,208 @@
+use std::{fmt::Display, ops::Deref, str::FromStr};
+
+use crate::{
+    compadecimal::{fmt_compa, CompaDecimal},
+    error::CompaDecimalError,
+    parse::{empty_input, invalid_byte},
+    utils::{digit_index, COMPA_DIGITS},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompaBuffer<const N: usize> {
+    bytes: [u8; N],
+    start: usize,
+}
+
+impl<const N: usize> CompaBuffer<N> {
+    pub const fn as_str(&self) -> &str {
+        // Only ever filled from COMPA_DIGITS, which is plain ASCII.
+        match std::str::from_utf8(self.as_bytes()) {
+            Ok(value) => value,
+            Err(_) => panic!("compa digits are ASCII"),
+        }
+    }
+
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.bytes.split_at(self.start).1
+    }
+
+    pub const fn len(&self) -> usize {
+        N - self.start
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<const N: usize> Deref for CompaBuffer<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for CompaBuffer<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> Display for CompaBuffer<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_compa(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for CompaBuffer<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DecodeFailure {
+    Empty,
+    InvalidDigit(usize),
+    Overflow,
+}
+
+macro_rules! compa_fixed {
+    ($name:ident, $t:ty, $len:expr) => {
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name($t);
+
+        impl $name {
+            pub const MAX_LEN: usize = $len;
+            pub const MIN: $name = $name(<$t>::MIN);
+            pub const MAX: $name = $name(<$t>::MAX);
+
+            pub const fn new(value: $t) -> $name {
+                $name(value)
+            }
+
+            pub const fn get(self) -> $t {
+                self.0
+            }
+
+            pub const fn encode(self) -> CompaBuffer<$len> {
+                let base = COMPA_DIGITS.len() as $t;
+                let mut bytes = [COMPA_DIGITS[0]; $len];
+                let mut start = $len;
+                let mut num = self.0;
+                loop {
+                    start -= 1;
+                    bytes[start] = COMPA_DIGITS[(num % base) as usize];
+                    num /= base;
+                    if num == 0 {
+                        break;
+                    }
+                }
+                CompaBuffer { bytes, start }
+            }
+
+            pub const fn decode(bytes: &[u8]) -> Option<$name> {
+                match $name::try_decode(bytes) {
+                    Ok(value) => Some(value),
+                    Err(_) => None,
+                }
+            }
+
+            pub fn parse(value: &str) -> Result<$name, CompaDecimalError> {
+                $name::try_decode(value.as_bytes()).map_err(|failure| match failure {
+                    DecodeFailure::Empty => empty_input(),
+                    DecodeFailure::InvalidDigit(offset) => {
+                        invalid_byte(value.as_bytes(), offset)
+                    }
+                    DecodeFailure::Overflow => CompaDecimalError {
+                        error_message: format!(
+                            "Overflow error! The compa value was too big to store in a {} data type",
+                            stringify!($t)
+                        ),
+                    },
+                })
+            }
+
+            const fn try_decode(bytes: &[u8]) -> Result<$name, DecodeFailure> {
+                if bytes.is_empty() {
+                    return Err(DecodeFailure::Empty);
+                }
+                let mut result: $t = 0;
+                let mut i = 0;
+                while i < bytes.len() {
+                    let index = match digit_index(bytes[i]) {
+                        Some(index) => index,
+                        None => return Err(DecodeFailure::InvalidDigit(i)),
+                    };
+                    result = match result.checked_mul(COMPA_DIGITS.len() as $t) {
+                        Some(result) => match result.checked_add(index as $t) {
+                            Some(result) => result,
+                            None => return Err(DecodeFailure::Overflow),
+                        },
+                        None => return Err(DecodeFailure::Overflow),
+                    };
+                    i += 1;
+                }
+                Ok($name(result))
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                fmt_compa(self.encode().as_str(), f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = CompaDecimalError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $name::parse(s)
+            }
+        }
+
+        impl From<$t> for $name {
+            fn from(value: $t) -> Self {
+                $name(value)
+            }
+        }
+
+        impl From<$name> for $t {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl From<$name> for CompaDecimal {
+            fn from(value: $name) -> Self {
+                CompaDecimal::from(value.0)
+            }
+        }
+
+        impl TryFrom<&CompaDecimal> for $name {
+            type Error = CompaDecimalError;
+
+            fn try_from(value: &CompaDecimal) -> Result<Self, Self::Error> {
+                Ok($name(value.to_decimal::<$t>()?))
+            }
+        }
+
+        impl TryFrom<CompaDecimal> for $name {
+            type Error = CompaDecimalError;
+
+            fn try_from(value: CompaDecimal) -> Result<Self, Self::Error> {
+                $name::try_from(&value)
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.encode().as_str() == *other
+            }
+        }
+    };
+}
+
+compa_fixed!(CompaU64, u64, 10);
+compa_fixed!(CompaU128, u128, 20);
@@ -0,0 +1,53 @@
+use crate::{
+    compadecimal::CompaDecimal, error::CompaDecimalError, parse::empty_input, utils::digit_value,
+};
+
+// Every printable ASCII character is a compa digit, so a separator has to come
+// from outside the alphabet for the grouped form to be read back unambiguously.
+fn check_separator(separator: char) -> Result<(), CompaDecimalError> {
+    if digit_value(separator).is_some() {
+        return Err(CompaDecimalError {
+            error_message: format!(
+                "Separator '{}' is a compa digit and cannot be used to group digits",
+                separator
+            ),
+        });
+    }
+    Ok(())
+}
+
+impl CompaDecimal {
+    // Groups are counted from the least significant digit, so only the first
+    // group can be shorter than `group_size`.
+    pub fn to_grouped(
+        &self,
+        group_size: usize,
+        separator: char,
+    ) -> Result<String, CompaDecimalError> {
+        check_separator(separator)?;
+        if group_size == 0 {
+            return Err(CompaDecimalError {
+                error_message: "Group size has to be greater than zero".to_string(),
+            });
+        }
+        let digits = self.get_value();
+        let mut result =
+            String::with_capacity(digits.len() + digits.len() / group_size * separator.len_utf8());
+        for (i, ch) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(group_size) {
+                result.push(separator);
+            }
+            result.push(ch);
+        }
+        Ok(result)
+    }
+
+    pub fn from_grouped(value: &str, separator: char) -> Result<CompaDecimal, CompaDecimalError> {
+        check_separator(separator)?;
+        let digits: String = value.chars().filter(|&ch| ch != separator).collect();
+        if digits.is_empty() {
+            return Err(empty_input());
+        }
+        digits.parse()
+    }
+}
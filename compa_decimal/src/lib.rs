@@ -1,6 +1,33 @@
+mod binary;
+mod capacity;
+pub mod compa_str;
 pub mod compadecimal;
+mod conversions;
+mod digits;
 pub mod error;
+pub mod fixed;
+mod grouped;
+pub mod literal;
+pub mod number_theory;
+mod numeric;
+mod ops;
+mod parse;
+mod radix;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod range;
+#[cfg(feature = "rusqlite")]
+pub mod sqlite;
+#[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+pub mod testing;
 mod utils;
 
-pub use compadecimal::CompaDecimal;
+pub use compa_str::CompaStr;
+pub use compadecimal::{CompaDecimal, ALTERNATE_PREFIX};
 pub use error::CompaDecimalError;
+pub use fixed::{CompaBuffer, CompaU128, CompaU64};
+pub use literal::CompaLiteral;
+#[cfg(feature = "rand")]
+pub use random::CompaUniform;
+pub use range::{CompaRange, CompaStepBy};
+pub use utils::{digit_char, digit_value, COMPA_DIGITS};
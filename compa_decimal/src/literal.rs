@@ -0,0 +1,100 @@
+use crate::{
+    compadecimal::CompaDecimal,
+    fixed::{CompaU128, CompaU64},
+    utils::{digit_index, COMPA_DIGITS},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompaLiteral(&'static str);
+
+impl CompaLiteral {
+    // Panics on invalid input, which turns into a compile error when evaluated in a const.
+    pub const fn new(value: &'static str) -> CompaLiteral {
+        let bytes = value.as_bytes();
+        if bytes.is_empty() {
+            panic!("compa literal cannot be empty");
+        }
+        let mut start = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if digit_index(bytes[i]).is_none() {
+                panic!("compa literal contains a character that is not a compa digit");
+            }
+            if start == i && bytes[i] == COMPA_DIGITS[0] && i + 1 < bytes.len() {
+                start += 1;
+            }
+            i += 1;
+        }
+        match std::str::from_utf8(bytes.split_at(start).1) {
+            Ok(normalized) => CompaLiteral(normalized),
+            Err(_) => panic!("compa literal is not valid UTF-8"),
+        }
+    }
+
+    pub const fn as_str(self) -> &'static str {
+        self.0
+    }
+
+    pub const fn to_u64(self) -> u64 {
+        match CompaU64::decode(self.0.as_bytes()) {
+            Some(value) => value.get(),
+            None => panic!("compa literal does not fit in a u64"),
+        }
+    }
+
+    pub const fn to_u128(self) -> u128 {
+        match CompaU128::decode(self.0.as_bytes()) {
+            Some(value) => value.get(),
+            None => panic!("compa literal does not fit in a u128"),
+        }
+    }
+}
+
+impl From<CompaLiteral> for CompaDecimal {
+    fn from(literal: CompaLiteral) -> Self {
+        literal
+            .as_str()
+            .parse()
+            .expect("a CompaLiteral only holds valid compa digits")
+    }
+}
+
+/// Checks a compa literal at compile time.
+///
+/// ```
+/// use compa_decimal::compa;
+///
+/// assert_eq!(compa!("1T~PC"), "1T~PC");
+/// assert_eq!(compa!(u64: "1T~PC"), 123456789);
+/// ```
+///
+/// A character outside the alphabet does not compile:
+///
+/// ```compile_fail,E0080
+/// use compa_decimal::compa;
+///
+/// let id = compa!("1T~P£");
+/// ```
+///
+/// Neither does a value that is too big for the requested integer:
+///
+/// ```compile_fail,E0080
+/// use compa_decimal::compa;
+///
+/// let id = compa!(u64: "91\"<n.hl48T!YkTkA?1Z");
+/// ```
+#[macro_export]
+macro_rules! compa {
+    (u64: $value:literal) => {{
+        const VALUE: u64 = $crate::CompaLiteral::new($value).to_u64();
+        VALUE
+    }};
+    (u128: $value:literal) => {{
+        const VALUE: u128 = $crate::CompaLiteral::new($value).to_u128();
+        VALUE
+    }};
+    ($value:literal) => {{
+        const LITERAL: $crate::CompaLiteral = $crate::CompaLiteral::new($value);
+        $crate::CompaDecimal::from(LITERAL)
+    }};
+}
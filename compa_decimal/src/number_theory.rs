@@ -0,0 +1,154 @@
+use num::{Integer, One, ToPrimitive, Zero};
+use num_bigint::BigUint;
+
+use crate::{
+    compadecimal::CompaDecimal,
+    error::CompaDecimalError,
+    ops::{from_biguint, to_biguint},
+};
+
+pub const DEFAULT_PRIME_ROUNDS: u32 = 24;
+
+// Testing against these bases is deterministic for every n below 2^64.
+const DETERMINISTIC_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+impl CompaDecimal {
+    pub fn gcd(&self, other: &CompaDecimal) -> CompaDecimal {
+        from_biguint(&to_biguint(self).gcd(&to_biguint(other)))
+    }
+
+    pub fn lcm(&self, other: &CompaDecimal) -> CompaDecimal {
+        from_biguint(&to_biguint(self).lcm(&to_biguint(other)))
+    }
+
+    pub fn mod_pow(
+        &self,
+        exponent: &CompaDecimal,
+        modulus: &CompaDecimal,
+    ) -> Result<CompaDecimal, CompaDecimalError> {
+        let modulus = non_zero_modulus(modulus)?;
+        Ok(from_biguint(
+            &to_biguint(self).modpow(&to_biguint(exponent), &modulus),
+        ))
+    }
+
+    pub fn mod_inverse(&self, modulus: &CompaDecimal) -> Result<CompaDecimal, CompaDecimalError> {
+        let modulus = non_zero_modulus(modulus)?;
+        match to_biguint(self).modinv(&modulus) {
+            Some(inverse) => Ok(from_biguint(&inverse)),
+            None => Err(CompaDecimalError {
+                error_message: format!(
+                    "{} has no inverse modulo {}",
+                    self.get_value(),
+                    from_biguint(&modulus)
+                ),
+            }),
+        }
+    }
+
+    pub fn isqrt(&self) -> CompaDecimal {
+        from_biguint(&to_biguint(self).sqrt())
+    }
+
+    pub fn ilog(&self, base: &CompaDecimal) -> Result<u64, CompaDecimalError> {
+        let num = to_biguint(self);
+        let base = to_biguint(base);
+        if num.is_zero() {
+            return Err(CompaDecimalError {
+                error_message: "Logarithm of zero is undefined".to_string(),
+            });
+        }
+        if base < BigUint::from(2u32) {
+            return Err(CompaDecimalError {
+                error_message: "Logarithm base has to be at least 2".to_string(),
+            });
+        }
+        // The bit-length estimate never overshoots, so only step upwards from it.
+        let mut log = (num.bits() - 1) / base.bits();
+        let mut power = base.pow(log as u32);
+        while &power * &base <= num {
+            power *= &base;
+            log += 1;
+        }
+        Ok(log)
+    }
+
+    pub fn is_probable_prime(&self) -> bool {
+        self.is_probable_prime_with_rounds(DEFAULT_PRIME_ROUNDS)
+    }
+
+    // Values below 2^64 are always tested against the deterministic bases, so the
+    // answer is exact there; above 2^64 `rounds` bases are tried, and at least one
+    // even when `rounds` is 0, so a composite is never waved through untested.
+    pub fn is_probable_prime_with_rounds(&self, rounds: u32) -> bool {
+        let n = to_biguint(self);
+        if n < BigUint::from(2u32) {
+            return false;
+        }
+        for &prime in DETERMINISTIC_BASES.iter() {
+            let prime = BigUint::from(prime);
+            if n == prime {
+                return true;
+            }
+            if (&n % &prime).is_zero() {
+                return false;
+            }
+        }
+
+        let n_minus_one = &n - BigUint::one();
+        let shift = n_minus_one
+            .trailing_zeros()
+            .expect("n - 1 is positive at this point");
+        let odd = &n_minus_one >> shift;
+
+        if n.to_u64().is_some() {
+            return DETERMINISTIC_BASES
+                .iter()
+                .all(|&base| witness_passes(&n, &BigUint::from(base), &odd, shift));
+        }
+
+        let mut state = n.iter_u64_digits().fold(0u64, |acc, x| acc ^ x);
+        let span = &n - BigUint::from(3u32);
+        (0..rounds.max(1) as usize).all(|round| {
+            let base = match DETERMINISTIC_BASES.get(round) {
+                Some(&base) => BigUint::from(base),
+                None => BigUint::from(splitmix64(&mut state)) % &span + BigUint::from(2u32),
+            };
+            witness_passes(&n, &base, &odd, shift)
+        })
+    }
+}
+
+fn non_zero_modulus(modulus: &CompaDecimal) -> Result<BigUint, CompaDecimalError> {
+    let modulus = to_biguint(modulus);
+    if modulus.is_zero() {
+        return Err(CompaDecimalError {
+            error_message: "Modulus cannot be zero".to_string(),
+        });
+    }
+    Ok(modulus)
+}
+
+// One Miller-Rabin round with n - 1 = odd * 2^shift.
+fn witness_passes(n: &BigUint, base: &BigUint, odd: &BigUint, shift: u64) -> bool {
+    let n_minus_one = n - BigUint::one();
+    let mut x = base.modpow(odd, n);
+    if x.is_one() || x == n_minus_one {
+        return true;
+    }
+    for _ in 1..shift {
+        x = &x * &x % n;
+        if x == n_minus_one {
+            return true;
+        }
+    }
+    false
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,126 @@
+use num::{
+    traits::Pow, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, Num, One,
+    ToPrimitive, Zero,
+};
+use num_bigint::BigUint;
+
+use crate::{
+    compadecimal::CompaDecimal,
+    error::CompaDecimalError,
+    ops::{from_biguint, to_biguint},
+};
+
+impl Zero for CompaDecimal {
+    fn zero() -> Self {
+        CompaDecimal::new()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.get_value().chars().all(|ch| ch == '0')
+    }
+}
+
+impl One for CompaDecimal {
+    fn one() -> Self {
+        from_biguint(&BigUint::one())
+    }
+
+    fn is_one(&self) -> bool {
+        self.get_value().trim_start_matches('0') == "1"
+    }
+}
+
+impl Num for CompaDecimal {
+    type FromStrRadixErr = CompaDecimalError;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        CompaDecimal::from_str_radix(str, radix)
+    }
+}
+
+impl CheckedAdd for CompaDecimal {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Some(self.add_compa(v))
+    }
+}
+
+impl CheckedSub for CompaDecimal {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        self.subtract_compa(v).ok()
+    }
+}
+
+impl CheckedMul for CompaDecimal {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        Some(self * v)
+    }
+}
+
+impl CheckedDiv for CompaDecimal {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        if v.is_zero() {
+            return None;
+        }
+        Some(self / v)
+    }
+}
+
+impl ToPrimitive for CompaDecimal {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_decimal::<u64>().ok()?.to_i64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.to_decimal::<u128>().ok()?.to_i128()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_decimal::<u64>().ok()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.to_decimal::<u128>().ok()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        to_biguint(self).to_f64()
+    }
+}
+
+impl FromPrimitive for CompaDecimal {
+    fn from_i64(n: i64) -> Option<Self> {
+        CompaDecimal::decimal_to_compa(n.to_u64()?).ok()
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        CompaDecimal::decimal_to_compa(n.to_u128()?).ok()
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        CompaDecimal::decimal_to_compa(n).ok()
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        CompaDecimal::decimal_to_compa(n).ok()
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(from_biguint(&BigUint::from_f64(n)?))
+    }
+}
+
+impl Pow<u32> for CompaDecimal {
+    type Output = CompaDecimal;
+
+    fn pow(self, rhs: u32) -> CompaDecimal {
+        Pow::pow(&self, rhs)
+    }
+}
+
+impl Pow<u32> for &CompaDecimal {
+    type Output = CompaDecimal;
+
+    fn pow(self, rhs: u32) -> CompaDecimal {
+        from_biguint(&to_biguint(self).pow(rhs))
+    }
+}
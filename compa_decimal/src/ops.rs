@@ -0,0 +1,134 @@
+use num_bigint::BigUint;
+use std::{
+    iter::{Product, Sum},
+    ops::{Add, Div, Mul, Rem, Sub},
+};
+
+use crate::{compa_str::CompaStr, compadecimal::CompaDecimal};
+
+pub(crate) fn to_biguint(compa: &CompaDecimal) -> BigUint {
+    compa
+        .to_biguint()
+        .expect("a CompaDecimal only holds valid compa digits")
+}
+
+pub(crate) fn from_biguint(num: &BigUint) -> CompaDecimal {
+    CompaDecimal::biguint_to_compa(num).expect("a BigUint always converts to a compa value")
+}
+
+impl Add<&CompaStr> for &CompaDecimal {
+    type Output = CompaDecimal;
+
+    fn add(self, rhs: &CompaStr) -> CompaDecimal {
+        self.add_compa(rhs)
+    }
+}
+
+impl Add<&CompaDecimal> for &CompaDecimal {
+    type Output = CompaDecimal;
+
+    fn add(self, rhs: &CompaDecimal) -> CompaDecimal {
+        self.add_compa(rhs)
+    }
+}
+
+impl Sub<&CompaStr> for &CompaDecimal {
+    type Output = CompaDecimal;
+
+    fn sub(self, rhs: &CompaStr) -> CompaDecimal {
+        match self.subtract_compa(rhs) {
+            Ok(result) => result,
+            Err(error) => panic!("attempt to subtract with overflow: {}", error),
+        }
+    }
+}
+
+impl Sub<&CompaDecimal> for &CompaDecimal {
+    type Output = CompaDecimal;
+
+    fn sub(self, rhs: &CompaDecimal) -> CompaDecimal {
+        self - &**rhs
+    }
+}
+
+impl Mul<&CompaDecimal> for &CompaDecimal {
+    type Output = CompaDecimal;
+
+    fn mul(self, rhs: &CompaDecimal) -> CompaDecimal {
+        from_biguint(&(to_biguint(self) * to_biguint(rhs)))
+    }
+}
+
+impl Div<&CompaDecimal> for &CompaDecimal {
+    type Output = CompaDecimal;
+
+    fn div(self, rhs: &CompaDecimal) -> CompaDecimal {
+        from_biguint(&(to_biguint(self) / to_biguint(rhs)))
+    }
+}
+
+impl Rem<&CompaDecimal> for &CompaDecimal {
+    type Output = CompaDecimal;
+
+    fn rem(self, rhs: &CompaDecimal) -> CompaDecimal {
+        from_biguint(&(to_biguint(self) % to_biguint(rhs)))
+    }
+}
+
+macro_rules! forward_binop {
+    ($imp:ident, $method:ident) => {
+        impl $imp<CompaDecimal> for CompaDecimal {
+            type Output = CompaDecimal;
+
+            fn $method(self, rhs: CompaDecimal) -> CompaDecimal {
+                $imp::$method(&self, &rhs)
+            }
+        }
+
+        impl $imp<&CompaDecimal> for CompaDecimal {
+            type Output = CompaDecimal;
+
+            fn $method(self, rhs: &CompaDecimal) -> CompaDecimal {
+                $imp::$method(&self, rhs)
+            }
+        }
+
+        impl $imp<CompaDecimal> for &CompaDecimal {
+            type Output = CompaDecimal;
+
+            fn $method(self, rhs: CompaDecimal) -> CompaDecimal {
+                $imp::$method(self, &rhs)
+            }
+        }
+    };
+}
+
+forward_binop!(Add, add);
+forward_binop!(Sub, sub);
+forward_binop!(Mul, mul);
+forward_binop!(Div, div);
+forward_binop!(Rem, rem);
+
+impl Sum for CompaDecimal {
+    fn sum<I: Iterator<Item = CompaDecimal>>(iter: I) -> Self {
+        iter.fold(CompaDecimal::new(), |acc, x| &acc + &x)
+    }
+}
+
+impl<'a> Sum<&'a CompaDecimal> for CompaDecimal {
+    fn sum<I: Iterator<Item = &'a CompaDecimal>>(iter: I) -> Self {
+        iter.fold(CompaDecimal::new(), |acc, x| &acc + x)
+    }
+}
+
+impl Product for CompaDecimal {
+    fn product<I: Iterator<Item = CompaDecimal>>(iter: I) -> Self {
+        from_biguint(&iter.map(|x| to_biguint(&x)).product())
+    }
+}
+
+impl<'a> Product<&'a CompaDecimal> for CompaDecimal {
+    fn product<I: Iterator<Item = &'a CompaDecimal>>(iter: I) -> Self {
+        from_biguint(&iter.map(to_biguint).product())
+    }
+}
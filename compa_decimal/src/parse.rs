@@ -0,0 +1,126 @@
+use crate::{
+    compadecimal::{CompaDecimal, ALTERNATE_PREFIX},
+    error::CompaDecimalError,
+    utils::{digit_index, digit_value},
+};
+
+pub(crate) fn empty_input() -> CompaDecimalError {
+    CompaDecimalError {
+        error_message: "Cannot parse an empty string".to_string(),
+    }
+}
+
+pub(crate) fn zero_length() -> CompaDecimalError {
+    CompaDecimalError {
+        error_message: "Length has to be at least one digit".to_string(),
+    }
+}
+
+pub(crate) fn invalid_char(offset: usize, ch: char) -> CompaDecimalError {
+    CompaDecimalError {
+        error_message: format!("Invalid compa digit {:?} at byte {}", ch, offset),
+    }
+}
+
+// Names the whole character when the bytes at `offset` are UTF-8, and falls back to
+// the raw byte otherwise, so a stray 0xFF in a network buffer is still reported.
+pub(crate) fn invalid_byte(bytes: &[u8], offset: usize) -> CompaDecimalError {
+    let chunk = bytes[offset..].utf8_chunks().next();
+    match chunk.and_then(|chunk| chunk.valid().chars().next()) {
+        Some(ch) => invalid_char(offset, ch),
+        None => CompaDecimalError {
+            error_message: format!(
+                "Invalid compa digit 0x{:02X} at byte {}",
+                bytes[offset], offset
+            ),
+        },
+    }
+}
+
+// Every constructor goes through here, so an empty string is rejected the same way
+// wherever it comes from instead of reading as zero in some places.
+pub(crate) fn check_digits(bytes: &[u8]) -> Result<(), CompaDecimalError> {
+    if bytes.is_empty() {
+        return Err(empty_input());
+    }
+    match bytes.iter().position(|&byte| digit_index(byte).is_none()) {
+        Some(offset) => Err(invalid_byte(bytes, offset)),
+        None => Ok(()),
+    }
+}
+
+impl CompaDecimal {
+    pub fn from_bytes_ascii(bytes: &[u8]) -> Result<CompaDecimal, CompaDecimalError> {
+        check_digits(bytes)?;
+        let value = String::from_utf8(bytes.to_vec()).expect("compa digits are ASCII");
+        Ok(CompaDecimal::from_valid_string(value))
+    }
+
+    // Reads `{:#}` output back. Both characters of ALTERNATE_PREFIX are compa digits, so
+    // the other parsers keep them as part of the value ("0c5" is 1430), and only this
+    // one, which requires the prefix, drops them.
+    pub fn from_alternate(value: &str) -> Result<CompaDecimal, CompaDecimalError> {
+        let Some(digits) = value.strip_prefix(ALTERNATE_PREFIX) else {
+            return Err(CompaDecimalError {
+                error_message: format!("Expected the {} prefix", ALTERNATE_PREFIX),
+            });
+        };
+        if digits.is_empty() {
+            return Err(empty_input());
+        }
+        match digits.bytes().position(|byte| digit_index(byte).is_none()) {
+            Some(offset) => Err(invalid_byte(
+                value.as_bytes(),
+                ALTERNATE_PREFIX.len() + offset,
+            )),
+            None => Ok(CompaDecimal::from_valid_string(digits.to_string())),
+        }
+    }
+
+    pub fn from_chars(
+        chars: impl IntoIterator<Item = char>,
+    ) -> Result<CompaDecimal, CompaDecimalError> {
+        let mut value = String::new();
+        for ch in chars {
+            if digit_value(ch).is_none() {
+                return Err(invalid_char(value.len(), ch));
+            }
+            value.push(ch);
+        }
+        if value.is_empty() {
+            return Err(empty_input());
+        }
+        Ok(CompaDecimal::from_valid_string(value))
+    }
+
+    // Reads digits up to the first non-digit and hands back the rest of the input.
+    // Every printable ASCII character is a digit, so values usually end at whitespace
+    // or a control byte. Input that does not start with a digit has no value to hand
+    // back, so this returns a `Result` like the other parsers instead of a bare pair.
+    pub fn parse_prefix(value: &str) -> Result<(CompaDecimal, &str), CompaDecimalError> {
+        CompaDecimal::parse_prefix_max(value, usize::MAX)
+    }
+
+    // Like `parse_prefix`, but stops after `max_len` digits, for fixed-width fields.
+    pub fn parse_prefix_max(
+        value: &str,
+        max_len: usize,
+    ) -> Result<(CompaDecimal, &str), CompaDecimalError> {
+        if max_len == 0 {
+            return Err(zero_length());
+        }
+        let len = value
+            .bytes()
+            .take(max_len)
+            .take_while(|&byte| digit_index(byte).is_some())
+            .count();
+        if len == 0 {
+            if value.is_empty() {
+                return Err(empty_input());
+            }
+            return Err(invalid_byte(value.as_bytes(), 0));
+        }
+        let (digits, rest) = value.split_at(len);
+        Ok((CompaDecimal::from_valid_string(digits.to_string()), rest))
+    }
+}
@@ -0,0 +1,88 @@
+use num_bigint::BigUint;
+
+use crate::{
+    compadecimal::CompaDecimal,
+    error::CompaDecimalError,
+    ops::{from_biguint, to_biguint},
+    parse::{empty_input, invalid_char},
+    utils::{digit_value, COMPA_DIGITS},
+};
+
+// `from_str_radix` and `to_string_radix` take the digits of every radix from the front
+// of the compa alphabet, so radix 16 is `0-9AaBbCc`, radix 95 is the compa form itself,
+// and `Num::from_str_radix` agrees with both. The `_std` pair follows the `0-9a-z`
+// convention of `u64::from_str_radix` instead, for hex and base-36 written elsewhere.
+const STD_RADIX_LIMIT: u32 = 36;
+
+fn check_radix(radix: u32, max: u32) -> Result<(), CompaDecimalError> {
+    if radix < 2 || radix > max {
+        return Err(CompaDecimalError {
+            error_message: format!("Radix has to be between 2 and {}, got {}", max, radix),
+        });
+    }
+    Ok(())
+}
+
+fn parse_digits(
+    value: &str,
+    radix: u32,
+    radix_digit_value: impl Fn(char) -> Option<u8>,
+) -> Result<CompaDecimal, CompaDecimalError> {
+    if value.is_empty() {
+        return Err(empty_input());
+    }
+    let digits = value
+        .char_indices()
+        .map(|(offset, ch)| {
+            radix_digit_value(ch).ok_or_else(|| {
+                let mut error = invalid_char(offset, ch);
+                error.error_message += &format!(" for radix {}", radix);
+                error
+            })
+        })
+        .collect::<Result<Vec<u8>, CompaDecimalError>>()?;
+    let num = BigUint::from_radix_be(&digits, radix).ok_or_else(|| CompaDecimalError {
+        error_message: format!("Failed to parse value in radix {}", radix),
+    })?;
+    Ok(from_biguint(&num))
+}
+
+impl CompaDecimal {
+    pub fn from_str_radix(value: &str, radix: u32) -> Result<CompaDecimal, CompaDecimalError> {
+        check_radix(radix, COMPA_DIGITS.len() as u32)?;
+        parse_digits(value, radix, |ch| {
+            digit_value(ch).filter(|&digit| u32::from(digit) < radix)
+        })
+    }
+
+    pub fn to_string_radix(&self, radix: u32) -> Result<String, CompaDecimalError> {
+        check_radix(radix, COMPA_DIGITS.len() as u32)?;
+        Ok(to_biguint(self)
+            .to_radix_be(radix)
+            .into_iter()
+            .map(|digit| COMPA_DIGITS[digit as usize] as char)
+            .collect())
+    }
+
+    // Case-insensitive, so "75bcd15" and "75BCD15" are the same hex value.
+    pub fn from_str_radix_std(value: &str, radix: u32) -> Result<CompaDecimal, CompaDecimalError> {
+        check_radix(radix, STD_RADIX_LIMIT)?;
+        parse_digits(value, radix, |ch| {
+            ch.to_digit(radix).map(|digit| digit as u8)
+        })
+    }
+
+    // Lowercase, like `format!("{:x}")`.
+    pub fn to_string_radix_std(&self, radix: u32) -> Result<String, CompaDecimalError> {
+        check_radix(radix, STD_RADIX_LIMIT)?;
+        Ok(to_biguint(self).to_str_radix(radix))
+    }
+
+    pub fn from_decimal_str(value: &str) -> Result<CompaDecimal, CompaDecimalError> {
+        CompaDecimal::from_str_radix(value, 10)
+    }
+
+    pub fn to_decimal_string(&self) -> String {
+        to_biguint(self).to_str_radix(10)
+    }
+}
@@ -0,0 +1,118 @@
+use num::{One, Zero};
+use num_bigint::BigUint;
+use rand::{distr::Distribution, Rng};
+use std::ops::{Bound, RangeBounds};
+
+use crate::{
+    compadecimal::CompaDecimal, error::CompaDecimalError, parse::zero_length,
+    utils::get_compa_digits,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompaUniform {
+    low: BigUint,
+    span: BigUint,
+}
+
+impl CompaUniform {
+    pub fn new<R>(range: R) -> Result<CompaUniform, CompaDecimalError>
+    where
+        R: RangeBounds<CompaDecimal>,
+    {
+        let low = match range.start_bound() {
+            Bound::Included(start) => start.to_biguint()?,
+            Bound::Excluded(start) => start.to_biguint()? + BigUint::one(),
+            Bound::Unbounded => BigUint::zero(),
+        };
+        let high = match range.end_bound() {
+            Bound::Included(end) => end.to_biguint()? + BigUint::one(),
+            Bound::Excluded(end) => end.to_biguint()?,
+            Bound::Unbounded => {
+                return Err(CompaDecimalError {
+                    error_message: "Cannot sample from a range without an upper bound".to_string(),
+                })
+            }
+        };
+        if high <= low {
+            return Err(CompaDecimalError {
+                error_message: "Cannot sample from an empty range".to_string(),
+            });
+        }
+        Ok(CompaUniform {
+            span: high - &low,
+            low,
+        })
+    }
+
+    pub fn below(bound: &CompaDecimal) -> Result<CompaUniform, CompaDecimalError> {
+        CompaUniform::new(..bound.clone())
+    }
+
+    pub fn with_len(len: usize) -> Result<CompaUniform, CompaDecimalError> {
+        if len == 0 {
+            return Err(zero_length());
+        }
+        let exponent = u32::try_from(len).map_err(|_| CompaDecimalError {
+            error_message: format!("Length {} is too large, the maximum is {}", len, u32::MAX),
+        })?;
+        let base = BigUint::from(get_compa_digits().len());
+        let low = if len == 1 {
+            BigUint::zero()
+        } else {
+            base.pow(exponent - 1)
+        };
+        let high = base.pow(exponent);
+        Ok(CompaUniform {
+            span: high - &low,
+            low,
+        })
+    }
+}
+
+impl Distribution<CompaDecimal> for CompaUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CompaDecimal {
+        let offset = uniform_below(&self.span, rng);
+        CompaDecimal::biguint_to_compa(&(&self.low + offset))
+            .expect("a BigUint always converts to a compa value")
+    }
+}
+
+// Rejection sampling over the smallest power of two covering `bound`, so every
+// value below `bound` is equally likely and fewer than two draws are expected.
+fn uniform_below<R: Rng + ?Sized>(bound: &BigUint, rng: &mut R) -> BigUint {
+    let bits = bound.bits();
+    let mut bytes = vec![0u8; bits.div_ceil(8) as usize];
+    let excess_bits = bytes.len() as u64 * 8 - bits;
+    loop {
+        rng.fill_bytes(&mut bytes);
+        bytes[0] &= 0xff >> excess_bits;
+        let candidate = BigUint::from_bytes_be(&bytes);
+        if &candidate < bound {
+            return candidate;
+        }
+    }
+}
+
+impl CompaDecimal {
+    pub fn random_below<R: Rng + ?Sized>(
+        bound: &CompaDecimal,
+        rng: &mut R,
+    ) -> Result<CompaDecimal, CompaDecimalError> {
+        Ok(CompaUniform::below(bound)?.sample(rng))
+    }
+
+    pub fn random_in_range<B, R>(range: B, rng: &mut R) -> Result<CompaDecimal, CompaDecimalError>
+    where
+        B: RangeBounds<CompaDecimal>,
+        R: Rng + ?Sized,
+    {
+        Ok(CompaUniform::new(range)?.sample(rng))
+    }
+
+    pub fn random_with_len<R: Rng + ?Sized>(
+        len: usize,
+        rng: &mut R,
+    ) -> Result<CompaDecimal, CompaDecimalError> {
+        Ok(CompaUniform::with_len(len)?.sample(rng))
+    }
+}
@@ -0,0 +1,192 @@
+use num::{One, ToPrimitive, Zero};
+use num_bigint::BigUint;
+use std::ops::{Range, RangeInclusive};
+
+use crate::{compadecimal::CompaDecimal, error::CompaDecimalError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompaRange {
+    front: BigUint,
+    back: BigUint,
+}
+
+impl CompaRange {
+    pub fn len(&self) -> BigUint {
+        if self.back > self.front {
+            &self.back - &self.front
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.back <= self.front
+    }
+
+    pub fn nth_compa(&mut self, n: &CompaDecimal) -> Option<CompaDecimal> {
+        let n = n.to_biguint().ok()?;
+        self.take_at(n)
+    }
+
+    pub fn nth_back_compa(&mut self, n: &CompaDecimal) -> Option<CompaDecimal> {
+        let n = n.to_biguint().ok()?;
+        self.take_back_at(n)
+    }
+
+    pub fn step_by_compa(self, step: &CompaDecimal) -> Result<CompaStepBy, CompaDecimalError> {
+        let step = step.to_biguint()?;
+        if step.is_zero() {
+            return Err(CompaDecimalError {
+                error_message: "Step has to be greater than zero".to_string(),
+            });
+        }
+        Ok(CompaStepBy {
+            range: self,
+            step,
+            first_take: true,
+        })
+    }
+
+    fn take_at(&mut self, n: BigUint) -> Option<CompaDecimal> {
+        let position = &self.front + n;
+        if position >= self.back {
+            self.front = self.back.clone();
+            return None;
+        }
+        self.front = &position + BigUint::one();
+        to_compa(&position)
+    }
+
+    fn take_back_at(&mut self, n: BigUint) -> Option<CompaDecimal> {
+        if n >= self.len() {
+            self.back = self.front.clone();
+            return None;
+        }
+        self.back -= n + BigUint::one();
+        to_compa(&self.back)
+    }
+}
+
+fn to_compa(num: &BigUint) -> Option<CompaDecimal> {
+    CompaDecimal::biguint_to_compa(num).ok()
+}
+
+impl Iterator for CompaRange {
+    type Item = CompaDecimal;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.take_at(BigUint::zero())
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.take_at(BigUint::from(n))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.len().to_usize() {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
+        }
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for CompaRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.take_back_at(BigUint::zero())
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.take_back_at(BigUint::from(n))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompaStepBy {
+    range: CompaRange,
+    step: BigUint,
+    first_take: bool,
+}
+
+impl CompaStepBy {
+    pub fn len(&self) -> BigUint {
+        let len = self.range.len();
+        if len.is_zero() {
+            return len;
+        }
+        if self.first_take {
+            (len - BigUint::one()) / &self.step + BigUint::one()
+        } else {
+            len / &self.step
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len().is_zero()
+    }
+}
+
+impl Iterator for CompaStepBy {
+    type Item = CompaDecimal;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = if self.first_take {
+            self.first_take = false;
+            BigUint::zero()
+        } else {
+            &self.step - BigUint::one()
+        };
+        self.range.take_at(n)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.len().to_usize() {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl DoubleEndedIterator for CompaStepBy {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.range.len();
+        if len.is_zero() {
+            return None;
+        }
+        // Offset of the last element still on the step grid, counted from the front.
+        let skip = if self.first_take {
+            BigUint::zero()
+        } else {
+            &self.step - BigUint::one()
+        };
+        if skip >= len {
+            self.range.back = self.range.front.clone();
+            return None;
+        }
+        let last = &len - BigUint::one();
+        let offset = &last - (&last - &skip) % &self.step;
+        self.range.take_back_at(last - offset)
+    }
+}
+
+impl CompaDecimal {
+    pub fn range(range: Range<CompaDecimal>) -> Result<CompaRange, CompaDecimalError> {
+        Ok(CompaRange {
+            front: range.start.to_biguint()?,
+            back: range.end.to_biguint()?,
+        })
+    }
+
+    pub fn range_inclusive(
+        range: RangeInclusive<CompaDecimal>,
+    ) -> Result<CompaRange, CompaDecimalError> {
+        let (start, end) = range.into_inner();
+        Ok(CompaRange {
+            front: start.to_biguint()?,
+            back: end.to_biguint()? + BigUint::one(),
+        })
+    }
+}
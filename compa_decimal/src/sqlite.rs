@@ -0,0 +1,115 @@
+use std::cmp::Ordering;
+
+use rusqlite::{
+    functions::FunctionFlags,
+    types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, Value, ValueRef},
+    Connection, Error,
+};
+
+use crate::{compa_str::CompaStr, compadecimal::CompaDecimal, error::CompaDecimalError};
+
+pub const COLLATION_NAME: &str = "COMPA";
+
+// Values are stored as TEXT without leading zeros, so `=` and UNIQUE agree with the
+// numeric value.
+impl ToSql for CompaDecimal {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Borrowed(ValueRef::Text(
+            self.normalized_value().as_bytes(),
+        )))
+    }
+}
+
+// Besides TEXT, non-negative INTEGERs and BLOBs holding the `to_bytes` form are read,
+// which covers columns that are halfway through a migration.
+impl FromSql for CompaDecimal {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Text(text) => std::str::from_utf8(text)
+                .map_err(|error| FromSqlError::Other(Box::new(error)))?
+                .parse()
+                .map_err(|error: CompaDecimalError| FromSqlError::Other(Box::new(error))),
+            ValueRef::Integer(num) => u64::try_from(num)
+                .map(CompaDecimal::from)
+                .map_err(|_| FromSqlError::OutOfRange(num)),
+            ValueRef::Blob(bytes) => CompaDecimal::from_bytes(bytes)
+                .map_err(|error| FromSqlError::Other(Box::new(error))),
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+// Valid compa strings sort numerically through `cmp_value`. Anything else sorts after
+// them in byte order, because a collation has no way to report an error.
+pub fn compare(a: &str, b: &str) -> Ordering {
+    match (CompaStr::new(a), CompaStr::new(b)) {
+        (Ok(a), Ok(b)) => a.cmp_value(b),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
+}
+
+pub fn register_collation(conn: &Connection) -> rusqlite::Result<()> {
+    conn.create_collation(COLLATION_NAME, compare)
+}
+
+pub fn register_functions(conn: &Connection) -> rusqlite::Result<()> {
+    let flags = FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC;
+    conn.create_scalar_function("compa_encode", 1, flags, |ctx| {
+        encode(ctx.get_raw(0)).map_err(user_error)
+    })?;
+    conn.create_scalar_function("compa_decode", 1, flags, |ctx| {
+        decode(ctx.get_raw(0)).map_err(user_error)
+    })
+}
+
+pub fn register(conn: &Connection) -> rusqlite::Result<()> {
+    register_collation(conn)?;
+    register_functions(conn)
+}
+
+fn user_error(error: CompaDecimalError) -> Error {
+    Error::UserFunctionError(Box::new(error))
+}
+
+// Takes an INTEGER, or TEXT holding a decimal number for values beyond i64.
+fn encode(value: ValueRef<'_>) -> Result<Value, CompaDecimalError> {
+    let compa = match value {
+        ValueRef::Null => return Ok(Value::Null),
+        ValueRef::Integer(num) => {
+            u64::try_from(num)
+                .map(CompaDecimal::from)
+                .map_err(|_| CompaDecimalError {
+                    error_message: format!(
+                        "compa_encode needs a non-negative integer, got {}",
+                        num
+                    ),
+                })?
+        }
+        ValueRef::Text(text) => CompaDecimal::from_decimal_str(&String::from_utf8_lossy(text))?,
+        _ => {
+            return Err(CompaDecimalError {
+                error_message: "compa_encode needs an integer or decimal text".to_string(),
+            })
+        }
+    };
+    Ok(Value::Text(compa.normalized_value().to_string()))
+}
+
+// Returns an INTEGER when the value fits in i64 and decimal TEXT otherwise.
+fn decode(value: ValueRef<'_>) -> Result<Value, CompaDecimalError> {
+    let compa = match value {
+        ValueRef::Null => return Ok(Value::Null),
+        ValueRef::Text(text) => String::from_utf8_lossy(text).parse::<CompaDecimal>()?,
+        _ => {
+            return Err(CompaDecimalError {
+                error_message: "compa_decode needs compa text".to_string(),
+            })
+        }
+    };
+    match i64::try_from(&compa) {
+        Ok(num) => Ok(Value::Integer(num)),
+        Err(_) => Ok(Value::Text(compa.to_decimal_string())),
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{compadecimal::CompaDecimal, utils::COMPA_DIGITS};
+
+// Generated values draw every digit from the full alphabet, so leading zeros, the
+// space and the backslash all show up instead of only letters and numbers.
+pub const MAX_GENERATED_LEN: usize = 40;
+
+fn from_digit_indexes(indexes: impl IntoIterator<Item = usize>) -> CompaDecimal {
+    let value = indexes
+        .into_iter()
+        .map(|index| COMPA_DIGITS[index] as char)
+        .collect();
+    CompaDecimal::from_valid_string(value)
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_impls {
+    use num::{PrimInt, Unsigned};
+    use proptest::{
+        arbitrary::{any, Arbitrary},
+        collection::vec,
+        strategy::{BoxedStrategy, Strategy},
+    };
+
+    use super::{from_digit_indexes, MAX_GENERATED_LEN};
+    use crate::{compadecimal::CompaDecimal, utils::COMPA_DIGITS};
+
+    pub fn any_compa() -> impl Strategy<Value = CompaDecimal> {
+        vec(0..COMPA_DIGITS.len(), 1..=MAX_GENERATED_LEN).prop_map(from_digit_indexes)
+    }
+
+    pub fn compa_up_to_max<T>() -> impl Strategy<Value = CompaDecimal>
+    where
+        T: PrimInt + Unsigned + Arbitrary,
+    {
+        any::<T>().prop_map(|num| {
+            CompaDecimal::decimal_to_compa(num).expect("an unsigned integer always converts")
+        })
+    }
+
+    // Exactly `len` digits without leading zeros, matching `CompaUniform::with_len`.
+    pub fn compa_with_len(len: usize) -> impl Strategy<Value = CompaDecimal> {
+        assert!(len > 0, "Length has to be at least one digit");
+        let first = if len == 1 { 0 } else { 1 };
+        (
+            first..COMPA_DIGITS.len(),
+            vec(0..COMPA_DIGITS.len(), len - 1),
+        )
+            .prop_map(|(first, rest)| from_digit_indexes(std::iter::once(first).chain(rest)))
+    }
+
+    impl Arbitrary for CompaDecimal {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<CompaDecimal>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            any_compa().boxed()
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+pub use proptest_impls::{any_compa, compa_up_to_max, compa_with_len};
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for CompaDecimal {
+    fn arbitrary(g: &mut quickcheck::Gen) -> CompaDecimal {
+        let max_len = g.size().clamp(1, MAX_GENERATED_LEN);
+        let len = <usize as quickcheck::Arbitrary>::arbitrary(g) % max_len + 1;
+        from_digit_indexes(
+            (0..len).map(|_| <usize as quickcheck::Arbitrary>::arbitrary(g) % COMPA_DIGITS.len()),
+        )
+    }
+
+    // Shrinks towards fewer digits: drop the leading zeros, then the most and the least
+    // significant digit.
+    fn shrink(&self) -> Box<dyn Iterator<Item = CompaDecimal>> {
+        let value = self.get_value();
+        let mut candidates = Vec::new();
+        if value.len() > 1 && value.starts_with(COMPA_DIGITS[0] as char) {
+            candidates.push(self.normalized_value().to_string());
+        }
+        if value.len() > 1 {
+            candidates.push(value[1..].to_string());
+            candidates.push(value[..value.len() - 1].to_string());
+        }
+        Box::new(candidates.into_iter().map(CompaDecimal::from_valid_string))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CompaDecimal {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<CompaDecimal> {
+        let len = u.int_in_range(1..=MAX_GENERATED_LEN)?;
+        let indexes = (0..len)
+            .map(|_| u.choose_index(COMPA_DIGITS.len()))
+            .collect::<arbitrary::Result<Vec<usize>>>()?;
+        Ok(from_digit_indexes(indexes))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(1 + MAX_GENERATED_LEN * 4))
+    }
+}
@@ -1,9 +1,39 @@
+pub const COMPA_DIGITS: &[u8; 95] =
+    b"0123456789AaBbCcDdEeFfGgHhIiJjKkLlMmNnOoPpQqRrSsTtUuVvWwXxYyZz!\"#$%&'()*+,-./:;<=>?@[\\]^_`|}{ ~";
+
+const NOT_A_DIGIT: u8 = u8::MAX;
+
+const DIGIT_LOOKUP: [u8; 256] = {
+    let mut lookup = [NOT_A_DIGIT; 256];
+    let mut i = 0;
+    while i < COMPA_DIGITS.len() {
+        lookup[COMPA_DIGITS[i] as usize] = i as u8;
+        i += 1;
+    }
+    lookup
+};
+
 pub fn get_compa_digits() -> Vec<char> {
-    "0123456789AaBbCcDdEeFfGgHhIiJjKkLlMmNnOoPpQqRrSsTtUuVvWwXxYyZz!\"#$%&'()*+,-./:;<=>?@[\\]^_`|}{ ~".chars().collect()
+    COMPA_DIGITS.iter().map(|&byte| byte as char).collect()
+}
+
+pub const fn digit_index(byte: u8) -> Option<u8> {
+    match DIGIT_LOOKUP[byte as usize] {
+        NOT_A_DIGIT => None,
+        index => Some(index),
+    }
+}
+
+pub fn digit_value(ch: char) -> Option<u8> {
+    if ch.is_ascii() {
+        digit_index(ch as u8)
+    } else {
+        None
+    }
 }
 
-pub fn valid_str(string: &str) -> bool {
-    string.chars().all(|ch| get_compa_digits().contains(&ch))
+pub fn digit_char(value: u8) -> Option<char> {
+    COMPA_DIGITS.get(value as usize).map(|&byte| byte as char)
 }
 
 #[cfg(test)]
@@ -11,13 +41,34 @@ mod tests {
     use super::*;
 
     #[test]
-    fn valid_str_test() {
-        assert!(valid_str(" abc"));
-        assert!(valid_str("ABC"));
-        assert!(valid_str("123"));
-        assert!(!valid_str("£"));
-        assert!(!valid_str("¬"));
-        assert!(!valid_str("カタカナ"));
-        assert!(!valid_str("片"));
+    fn valid_chars_test() {
+        let valid = |string: &str| string.chars().all(|ch| digit_value(ch).is_some());
+        assert!(valid(" abc"));
+        assert!(valid("ABC"));
+        assert!(valid("123"));
+        assert!(!valid("£"));
+        assert!(!valid("¬"));
+        assert!(!valid("カタカナ"));
+        assert!(!valid("片"));
+    }
+
+    #[test]
+    fn digit_index_test() {
+        for (i, &byte) in COMPA_DIGITS.iter().enumerate() {
+            assert_eq!(digit_index(byte), Some(i as u8));
+        }
+        assert_eq!(digit_index(b'\t'), None);
+        assert_eq!(digit_index(0xC2), None);
+    }
+
+    #[test]
+    fn digit_value_char_test() {
+        for (i, ch) in get_compa_digits().into_iter().enumerate() {
+            assert_eq!(digit_value(ch), Some(i as u8));
+            assert_eq!(digit_char(i as u8), Some(ch));
+        }
+        assert_eq!(digit_value('£'), None);
+        assert_eq!(digit_value('\u{C2}'), None);
+        assert_eq!(digit_char(95), None);
     }
 }
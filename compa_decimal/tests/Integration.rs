@@ -1,5 +1,5 @@
 use compa_decimal::*;
-use num::{BigInt, BigUint, One, Zero};
+use num::{BigUint, One, Zero};
 use std::cmp::Ordering;
 
 #[test]
@@ -277,4 +277,1143 @@ fn cmp_str_test() {
         compa_decimal1.cmp_str("df$fG35SDd$%FDgfd2d").unwrap(),
         Ordering::Less
     );
+
+    let compa_decimal1: CompaDecimal = "01".parse().unwrap();
+    assert_eq!(compa_decimal1.cmp_str("2").unwrap(), Ordering::Less);
+    assert_eq!(compa_decimal1.cmp_str("0001").unwrap(), Ordering::Equal);
+    assert!(compa_decimal1.cmp_str("").is_err());
+}
+
+#[test]
+fn parse_empty_test() {
+    assert_eq!(
+        "".parse::<CompaDecimal>().unwrap_err().error_message,
+        "Cannot parse an empty string"
+    );
+    assert!(CompaDecimal::try_from("").is_err());
+    assert!(CompaDecimal::from_bytes_ascii(b"").is_err());
+    assert!(CompaDecimal::from_chars("".chars()).is_err());
+    let compa: CompaDecimal = "1T~PC".parse().unwrap();
+    assert!(compa.add("").is_err());
+    assert!(compa.subtract("").is_err());
+}
+
+#[test]
+fn parse_error_position_test() {
+    assert_eq!(
+        "123asd£".parse::<CompaDecimal>().unwrap_err().error_message,
+        "Invalid compa digit '£' at byte 6"
+    );
+    assert_eq!(
+        CompaDecimal::try_from("1T\n").unwrap_err().error_message,
+        "Invalid compa digit '\\n' at byte 2"
+    );
+    assert_eq!(
+        CompaStr::new("カタ").unwrap_err().error_message,
+        "Invalid compa digit 'カ' at byte 0"
+    );
+    assert_eq!(
+        CompaU64::parse("1T\t").unwrap_err().error_message,
+        "Invalid compa digit '\\t' at byte 2"
+    );
+}
+
+#[test]
+fn from_bytes_ascii_test() {
+    let compa_decimal = CompaDecimal::from_bytes_ascii(b"1T~PC").unwrap();
+    assert_eq!(compa_decimal, "1T~PC");
+    assert_eq!(
+        CompaDecimal::from_bytes_ascii(b"1T\xFFPC")
+            .unwrap_err()
+            .error_message,
+        "Invalid compa digit 0xFF at byte 2"
+    );
+    assert_eq!(
+        CompaDecimal::from_bytes_ascii("12£".as_bytes())
+            .unwrap_err()
+            .error_message,
+        "Invalid compa digit '£' at byte 2"
+    );
+    assert!(CompaDecimal::from_bytes_ascii(b"").is_err());
+}
+
+#[test]
+fn from_chars_test() {
+    let compa_decimal = CompaDecimal::from_chars("2$".chars()).unwrap();
+    assert_eq!(compa_decimal.to_decimal::<u32>().unwrap(), 255);
+    assert_eq!(
+        CompaDecimal::from_chars(['£', 'a'])
+            .unwrap_err()
+            .error_message,
+        "Invalid compa digit '£' at byte 0"
+    );
+    assert_eq!(
+        CompaDecimal::from_chars("ab片".chars())
+            .unwrap_err()
+            .error_message,
+        "Invalid compa digit '片' at byte 2"
+    );
+    assert!(CompaDecimal::from_chars(std::iter::empty()).is_err());
+}
+
+#[test]
+fn parse_prefix_test() {
+    let (compa_decimal, rest) = CompaDecimal::parse_prefix("1T~PC\t2$\n").unwrap();
+    assert_eq!(compa_decimal, "1T~PC");
+    assert_eq!(rest, "\t2$\n");
+
+    let (compa_decimal, rest) = CompaDecimal::parse_prefix_max("1T~PC", 2).unwrap();
+    assert_eq!(compa_decimal, "1T");
+    assert_eq!(rest, "~PC");
+
+    let (compa_decimal, rest) = CompaDecimal::parse_prefix_max("2$", 5).unwrap();
+    assert_eq!(compa_decimal, "2$");
+    assert_eq!(rest, "");
+
+    assert_eq!(
+        CompaDecimal::parse_prefix("\t2$")
+            .unwrap_err()
+            .error_message,
+        "Invalid compa digit '\\t' at byte 0"
+    );
+    assert!(CompaDecimal::parse_prefix("").is_err());
+    assert_eq!(
+        CompaDecimal::parse_prefix_max("1T~PC", 0)
+            .unwrap_err()
+            .error_message,
+        "Length has to be at least one digit"
+    );
+}
+
+#[test]
+fn byte_char_prefix_parsers_agree_test() {
+    // None of them treats the `{:#}` prefix specially, so they all read the same digits.
+    let parsed: CompaDecimal = "0c5".parse().unwrap();
+    assert_eq!(CompaDecimal::from_bytes_ascii(b"0c5").unwrap(), parsed);
+    assert_eq!(CompaDecimal::from_chars("0c5".chars()).unwrap(), parsed);
+    assert_eq!(CompaDecimal::parse_prefix("0c5\t").unwrap().0, parsed);
+    assert_eq!(CompaDecimal::parse_prefix_max("0c5\t", 2).unwrap().0, "0c");
+}
+
+#[test]
+fn subtract_leading_zeros_test() {
+    let compa_decimal: CompaDecimal = "5".parse().unwrap();
+    assert_eq!(compa_decimal.subtract("0005").unwrap(), "0");
+    assert_eq!(compa_decimal.subtract("0004").unwrap(), "1");
+    let compa_decimal: CompaDecimal = "0".parse().unwrap();
+    assert_eq!(compa_decimal.subtract("000").unwrap(), "0");
+    assert!(compa_decimal.subtract("001").is_err());
+}
+
+#[test]
+fn compa_str_new_test() {
+    let compa_str = CompaStr::new("1T~PC").unwrap();
+    assert_eq!(compa_str, &"1T~PC");
+    assert_eq!(compa_str.len(), 5);
+    assert!(CompaStr::new("123asd£").is_err());
+    assert!(CompaStr::new("").is_err());
+
+    let buffer = "1T~PC\t2$\n";
+    let id: &CompaStr = buffer[..5].try_into().unwrap();
+    let parent = CompaStr::new(&buffer[6..8]).unwrap();
+    assert_eq!(id.to_string(), "1T~PC");
+    assert_eq!(format!("{:#}", parent), "0c2$");
+    assert!(CompaStr::new(&buffer[..6]).is_err());
+}
+
+#[test]
+fn compa_str_owned_test() {
+    let compa_str = CompaStr::new("2$").unwrap();
+    let owned: CompaDecimal = compa_str.to_owned();
+    assert_eq!(owned, "2$");
+    assert_eq!(owned.to_decimal::<u32>().unwrap(), 255);
+
+    let borrowed: &CompaStr = &owned;
+    assert_eq!(borrowed, compa_str);
+    assert_eq!(owned.as_str(), "2$");
+
+    let cow: std::borrow::Cow<CompaStr> = std::borrow::Cow::Borrowed(compa_str);
+    assert_eq!(cow.into_owned(), owned);
+}
+
+#[test]
+fn compa_str_cmp_test() {
+    let a = CompaStr::new("0005").unwrap();
+    let b = CompaStr::new("5").unwrap();
+    assert_eq!(a.cmp_value(b), Ordering::Equal);
+    assert_eq!(a.cmp(b), Ordering::Greater);
+    assert_eq!(a.normalized(), b);
+    assert_eq!(CompaStr::new("000").unwrap().normalized(), &"0");
+
+    let small: CompaDecimal = "2$".parse().unwrap();
+    let large: CompaDecimal = "1T~PC".parse().unwrap();
+    assert_eq!(small.cmp(&large), (*small).cmp(&*large));
+    assert_eq!(large.cmp_value(&small), Ordering::Greater);
+
+    let padded: CompaDecimal = "01".parse().unwrap();
+    let two: CompaDecimal = "2".parse().unwrap();
+    assert!(padded > two);
+    assert_eq!(padded.cmp_str("2").unwrap(), Ordering::Less);
+}
+
+#[test]
+fn compa_str_arithmetic_test() {
+    let compa_decimal: CompaDecimal = "ASr35".parse().unwrap();
+    let addend = CompaStr::new("as1Ad4").unwrap();
+    let sum = compa_decimal.add_compa(addend);
+    assert_eq!(sum, compa_decimal.add("as1Ad4").unwrap());
+    assert_eq!(&compa_decimal + addend, sum);
+
+    assert_eq!(sum.subtract_compa(addend).unwrap(), compa_decimal);
+    assert_eq!(&sum - addend, compa_decimal);
+    assert!(compa_decimal.subtract_compa(addend).is_err());
+
+    let five: CompaDecimal = "5".parse().unwrap();
+    assert_eq!(
+        five.subtract_compa(CompaStr::new("0005").unwrap()).unwrap(),
+        "0"
+    );
+}
+
+#[test]
+fn range_test() {
+    let start: CompaDecimal = "1z".parse().unwrap();
+    let end: CompaDecimal = "1#".parse().unwrap();
+    let values: Vec<CompaDecimal> = CompaDecimal::range(start.clone()..end.clone())
+        .unwrap()
+        .collect();
+    assert_eq!(values, vec!["1z", "1!", "1\""]);
+
+    let values: Vec<CompaDecimal> = CompaDecimal::range_inclusive(start.clone()..=end.clone())
+        .unwrap()
+        .rev()
+        .collect();
+    assert_eq!(values, vec!["1#", "1\"", "1!", "1z"]);
+
+    let range = CompaDecimal::range(end.clone()..start.clone()).unwrap();
+    assert!(range.is_empty());
+    assert_eq!(range.count(), 0);
+
+    let values: Vec<CompaDecimal> =
+        CompaDecimal::range("1}".parse().unwrap().."21".parse().unwrap())
+            .unwrap()
+            .collect();
+    assert_eq!(values, vec!["1}", "1{", "1 ", "1~", "20"]);
+}
+
+#[test]
+fn range_len_test() {
+    let range = CompaDecimal::range("0".parse().unwrap().."100".parse().unwrap()).unwrap();
+    assert_eq!(range.len(), BigUint::from(9025u32));
+    assert_eq!(range.size_hint(), (9025, Some(9025)));
+
+    let range = CompaDecimal::range_inclusive(
+        "0".parse().unwrap()..="This is a test for a long text".parse().unwrap(),
+    )
+    .unwrap();
+    let expected = "This is a test for a long text"
+        .parse::<CompaDecimal>()
+        .unwrap()
+        .to_biguint()
+        .unwrap()
+        + BigUint::one();
+    assert_eq!(range.len(), expected);
+    assert_eq!(range.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn range_nth_test() {
+    let mut range = CompaDecimal::range("0".parse().unwrap().."100".parse().unwrap()).unwrap();
+    assert_eq!(range.nth(95).unwrap(), "10");
+    assert_eq!(range.next().unwrap(), "11");
+    assert_eq!(range.nth_back(0).unwrap(), "~~");
+    assert_eq!(range.nth_compa(&"1".parse().unwrap()).unwrap(), "13");
+    assert_eq!(range.nth_back_compa(&"10".parse().unwrap()).unwrap(), "  ");
+    assert!(range.nth(100_000).is_none());
+    assert!(range.next().is_none());
+}
+
+#[test]
+fn range_step_by_test() {
+    let range = CompaDecimal::range("0".parse().unwrap().."A".parse().unwrap()).unwrap();
+    let values: Vec<CompaDecimal> = range.step_by(3).collect();
+    assert_eq!(values, vec!["0", "3", "6", "9"]);
+
+    let range = CompaDecimal::range("0".parse().unwrap().."A".parse().unwrap()).unwrap();
+    let stepped = range.step_by_compa(&"4".parse().unwrap()).unwrap();
+    assert_eq!(stepped.len(), BigUint::from(3u32));
+    let values: Vec<CompaDecimal> = stepped.clone().collect();
+    assert_eq!(values, vec!["0", "4", "8"]);
+    let values: Vec<CompaDecimal> = stepped.rev().collect();
+    assert_eq!(values, vec!["8", "4", "0"]);
+
+    let mut stepped = CompaDecimal::range("0".parse().unwrap().."B".parse().unwrap())
+        .unwrap()
+        .step_by_compa(&"4".parse().unwrap())
+        .unwrap();
+    assert_eq!(stepped.next().unwrap(), "0");
+    assert_eq!(stepped.next_back().unwrap(), "8");
+    assert_eq!(stepped.next().unwrap(), "4");
+    assert!(stepped.next_back().is_none());
+
+    let range = CompaDecimal::range("0".parse().unwrap().."A".parse().unwrap()).unwrap();
+    assert!(range.step_by_compa(&CompaDecimal::new()).is_err());
+}
+
+#[test]
+fn arithmetic_operators_test() {
+    let a: CompaDecimal = "aAswf".parse().unwrap();
+    let b: CompaDecimal = "AsdgrW11".parse().unwrap();
+    assert_eq!(&a + &b, "AsdMX6XG");
+    assert_eq!(a.clone() + b.clone(), "AsdMX6XG");
+    assert_eq!(&b - &a, b.subtract("aAswf").unwrap());
+    assert_eq!(&(&b - &a) + &a, b);
+
+    let a: CompaDecimal = "1T~PC".parse().unwrap();
+    let b: CompaDecimal = "b~".parse().unwrap();
+    let product = &a * &b;
+    assert_eq!(
+        product.to_biguint().unwrap(),
+        BigUint::from(123456789u64 * 1329)
+    );
+    assert_eq!(&product / &b, "1T~PC");
+    assert_eq!(
+        &a % &b,
+        CompaDecimal::decimal_to_compa::<u64>(123456789 % 1329).unwrap()
+    );
+}
+
+#[test]
+#[should_panic]
+fn sub_operator_underflow_test() {
+    let a: CompaDecimal = "1".parse().unwrap();
+    let b: CompaDecimal = "2".parse().unwrap();
+    let _ = a - b;
+}
+
+#[test]
+fn num_traits_test() {
+    use num::{
+        traits::Pow, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, Num,
+        ToPrimitive,
+    };
+
+    assert_eq!(CompaDecimal::zero(), "0");
+    assert!(CompaDecimal::zero().is_zero());
+    assert!("000".parse::<CompaDecimal>().unwrap().is_zero());
+    assert_eq!(CompaDecimal::one(), "1");
+    assert!("01".parse::<CompaDecimal>().unwrap().is_one());
+
+    let one = CompaDecimal::one();
+    let two: CompaDecimal = "2".parse().unwrap();
+    assert_eq!(one.checked_add(&two).unwrap(), "3");
+    assert!(one.checked_sub(&two).is_none());
+    assert_eq!(two.checked_sub(&one).unwrap(), "1");
+    assert_eq!(two.checked_mul(&two).unwrap(), "4");
+    assert!(two.checked_div(&CompaDecimal::zero()).is_none());
+    assert_eq!(two.checked_div(&two).unwrap(), "1");
+
+    let compa: CompaDecimal = "1T~PC".parse().unwrap();
+    assert_eq!(compa.to_u64(), Some(123456789));
+    assert_eq!(compa.to_i32(), Some(123456789));
+    assert_eq!(compa.to_u16(), None);
+    assert_eq!(compa.to_f64(), Some(123456789.0));
+    let max: CompaDecimal = "91\"<n.hl48T!YkTkA?1Z".parse().unwrap();
+    assert_eq!(max.to_u128(), Some(u128::MAX));
+    assert_eq!(max.to_i128(), None);
+
+    assert_eq!(CompaDecimal::from_u64(123456789).unwrap(), "1T~PC");
+    assert_eq!(CompaDecimal::from_i32(-1), None);
+    assert_eq!(CompaDecimal::from_u128(u128::MAX).unwrap(), max);
+    assert_eq!(CompaDecimal::from_f64(16.7).unwrap(), "D");
+
+    assert_eq!(
+        <CompaDecimal as Num>::from_str_radix("123456789", 10).unwrap(),
+        "1T~PC"
+    );
+    assert_eq!(
+        <CompaDecimal as Num>::from_str_radix("75aBb15", 16).unwrap(),
+        "1T~PC"
+    );
+    // The compa alphabet orders digits 0-9AaBbCc, so this is not the usual hex.
+    assert!(<CompaDecimal as Num>::from_str_radix("75bcd15", 16).is_err());
+    assert_eq!(
+        <CompaDecimal as Num>::from_str_radix("1T~PC", 95).unwrap(),
+        "1T~PC"
+    );
+    assert!(<CompaDecimal as Num>::from_str_radix("12", 1).is_err());
+    assert!(<CompaDecimal as Num>::from_str_radix("12", 96).is_err());
+    assert!(<CompaDecimal as Num>::from_str_radix("1G", 16).is_err());
+    assert!(<CompaDecimal as Num>::from_str_radix("", 10).is_err());
+
+    let base: CompaDecimal = "10".parse().unwrap();
+    assert_eq!(Pow::pow(&base, 3), "1000");
+    assert_eq!(Pow::pow(two, 0), "1");
+}
+
+#[test]
+fn sum_product_test() {
+    use num::ToPrimitive;
+
+    let values: Vec<CompaDecimal> = ["1", "~", "A"].iter().map(|x| x.parse().unwrap()).collect();
+    let sum: CompaDecimal = values.iter().sum();
+    assert_eq!(sum.to_u64(), Some(1 + 94 + 10));
+    let sum: CompaDecimal = values.clone().into_iter().sum();
+    assert_eq!(sum.to_u64(), Some(105));
+    let product: CompaDecimal = values.iter().product();
+    assert_eq!(product.to_u64(), Some(940));
+    let product: CompaDecimal = values.into_iter().product();
+    assert_eq!(product.to_u64(), Some(940));
+
+    let empty: Vec<CompaDecimal> = Vec::new();
+    assert_eq!(empty.iter().sum::<CompaDecimal>(), "0");
+    assert_eq!(empty.iter().product::<CompaDecimal>(), "1");
+}
+
+#[test]
+fn from_unsigned_test() {
+    assert_eq!(CompaDecimal::from(16u8), "D");
+    assert_eq!(CompaDecimal::from(1329u16), "b~");
+    assert_eq!(CompaDecimal::from(1329u32), "b~");
+    assert_eq!(CompaDecimal::from(27068251u64), "kWg}");
+    assert_eq!(CompaDecimal::from(u128::MAX), "91\"<n.hl48T!YkTkA?1Z");
+    assert_eq!(CompaDecimal::from(0usize), "0");
+    let compa: CompaDecimal = BigUint::from(123u32).into();
+    assert_eq!(compa, "1J");
+    let compa: CompaDecimal = (&BigUint::from(123u32)).into();
+    assert_eq!(compa, "1J");
+}
+
+#[test]
+fn try_from_compa_test() {
+    let compa: CompaDecimal = "b~".parse().unwrap();
+    assert_eq!(u16::try_from(&compa).unwrap(), 1329);
+    assert_eq!(u32::try_from(&compa).unwrap(), 1329);
+    assert_eq!(usize::try_from(&compa).unwrap(), 1329);
+    assert_eq!(i16::try_from(&compa).unwrap(), 1329);
+    assert!(u8::try_from(&compa).is_err());
+    assert!(i8::try_from(&compa).is_err());
+
+    let compa: CompaDecimal = "1J".parse().unwrap();
+    assert_eq!(u8::try_from(&compa).unwrap(), 123);
+    assert_eq!(i8::try_from(&compa).unwrap(), 123);
+    let compa: CompaDecimal = "1~".parse().unwrap();
+    assert_eq!(u8::try_from(&compa).unwrap(), 189);
+    assert!(i8::try_from(&compa).is_err());
+
+    let max: CompaDecimal = "91\"<n.hl48T!YkTkA?1Z".parse().unwrap();
+    assert_eq!(u128::try_from(&max).unwrap(), u128::MAX);
+    assert!(i128::try_from(&max).is_err());
+    assert!(u64::try_from(max.clone()).is_err());
+    assert_eq!(BigUint::from(&max), BigUint::from(u128::MAX));
+    assert_eq!(BigUint::from(max), BigUint::from(u128::MAX));
+}
+
+#[test]
+fn conversions_with_question_mark_test() {
+    fn round_trip(num: u64) -> Result<u64, CompaDecimalError> {
+        let compa: CompaDecimal = num.into();
+        let back: u64 = (&compa).try_into()?;
+        Ok(back)
+    }
+    assert_eq!(round_trip(123456789).unwrap(), 123456789);
+
+    fn narrow(compa: &CompaDecimal) -> Result<u8, CompaDecimalError> {
+        let num = u8::try_from(compa)?;
+        Ok(num)
+    }
+    assert!(narrow(&CompaDecimal::from(256u32)).is_err());
+}
+
+#[test]
+fn compa_u64_test() {
+    let compa = CompaU64::new(123456789);
+    assert_eq!(compa.encode(), "1T~PC");
+    assert_eq!(compa.to_string(), "1T~PC");
+    assert_eq!(CompaU64::new(0).encode(), "0");
+    assert_eq!(CompaU64::MAX.encode().len(), CompaU64::MAX_LEN);
+    assert_eq!(
+        CompaU64::MAX.encode().as_str(),
+        CompaDecimal::from(u64::MAX).get_value()
+    );
+
+    let parsed: CompaU64 = "1T~PC".parse().unwrap();
+    assert_eq!(parsed.get(), 123456789);
+    assert_eq!(CompaU64::parse("001T~PC").unwrap(), compa);
+    assert!(CompaU64::parse("").is_err());
+    assert!(CompaU64::parse("1T~PC£").is_err());
+    assert!(CompaU64::parse("91\"<n.hl48T!YkTkA?1Z").is_err());
+
+    let copied = compa;
+    assert_eq!(copied, compa);
+    assert!(CompaU64::new(94) < CompaU64::new(95));
+}
+
+#[test]
+fn compa_u128_test() {
+    let compa = CompaU128::new(u128::MAX);
+    assert_eq!(compa.encode(), "91\"<n.hl48T!YkTkA?1Z");
+    assert_eq!(compa.encode().len(), CompaU128::MAX_LEN);
+    let parsed: CompaU128 = "91\"<n.hl48T!YkTkA?1Z".parse().unwrap();
+    assert_eq!(parsed, CompaU128::MAX);
+    assert!(CompaU128::parse("100000000000000000000").is_err());
+
+    for value in [0u128, 1, 94, 95, 565984502558084335516371423] {
+        let fixed = CompaU128::new(value);
+        assert_eq!(
+            fixed.encode().as_str(),
+            CompaDecimal::from(value).get_value()
+        );
+    }
+}
+
+#[test]
+fn compa_fixed_conversions_test() {
+    let compa: CompaDecimal = CompaU64::new(27068251).into();
+    assert_eq!(compa, "kWg}");
+    assert_eq!(CompaU64::try_from(&compa).unwrap().get(), 27068251);
+    assert_eq!(u64::from(CompaU64::from(27068251u64)), 27068251);
+
+    let big: CompaDecimal = "91\"<n.hl48T!YkTkA?1Z".parse().unwrap();
+    assert!(CompaU64::try_from(&big).is_err());
+    assert_eq!(CompaU128::try_from(big).unwrap(), CompaU128::MAX);
+}
+
+#[test]
+fn compa_macro_test() {
+    let compa = compa!("1T~PC");
+    assert_eq!(compa, "1T~PC");
+    assert_eq!(compa!("0001T~PC"), "1T~PC");
+    assert_eq!(compa!("000"), "0");
+    assert_eq!(compa!(" ~"), " ~");
+
+    const ID: u128 = compa!(u128: "1T~PC");
+    assert_eq!(ID, 123456789);
+    const MAX: u128 = compa!(u128: "91\"<n.hl48T!YkTkA?1Z");
+    assert_eq!(MAX, u128::MAX);
+    const SMALL: u64 = compa!(u64: "00kWg}");
+    assert_eq!(SMALL, 27068251);
+}
+
+#[test]
+fn compa_literal_test() {
+    const LITERAL: CompaLiteral = CompaLiteral::new("00b~");
+    assert_eq!(LITERAL.as_str(), "b~");
+    assert_eq!(LITERAL.to_u64(), 1329);
+    assert_eq!(CompaDecimal::from(LITERAL), "b~");
+}
+
+#[test]
+#[should_panic(expected = "not a compa digit")]
+fn compa_literal_invalid_digit_test() {
+    CompaLiteral::new("1T~PC£");
+}
+
+#[test]
+#[should_panic(expected = "does not fit in a u64")]
+fn compa_literal_overflow_test() {
+    CompaLiteral::new("91\"<n.hl48T!YkTkA?1Z").to_u64();
+}
+
+#[test]
+fn const_encode_decode_test() {
+    const KEY: CompaU64 = CompaU64::new(123456789);
+    const KEY_DIGITS: CompaBuffer<10> = KEY.encode();
+    const KEY_STR: &str = KEY_DIGITS.as_str();
+    assert_eq!(KEY_STR, "1T~PC");
+    assert_eq!(KEY_DIGITS.len(), 5);
+
+    const DECODED: Option<CompaU64> = CompaU64::decode(b"1T~PC");
+    assert_eq!(DECODED, Some(KEY));
+    const INVALID: Option<CompaU64> = CompaU64::decode("1T~PC£".as_bytes());
+    assert_eq!(INVALID, None);
+    const EMPTY: Option<CompaU64> = CompaU64::decode(b"");
+    assert_eq!(EMPTY, None);
+
+    const MAX: CompaBuffer<20> = CompaU128::MAX.encode();
+    assert_eq!(MAX.as_bytes(), b"91\"<n.hl48T!YkTkA?1Z");
+    const OVERFLOW: Option<CompaU128> = CompaU128::decode(b"100000000000000000000");
+    assert_eq!(OVERFLOW, None);
+    assert_eq!(CompaU128::decode(MAX.as_bytes()), Some(CompaU128::MAX));
+}
+
+#[test]
+fn shl_shr_digits_test() {
+    let compa: CompaDecimal = "1T~PC".parse().unwrap();
+    assert_eq!(compa.shl_digits(0), "1T~PC");
+    assert_eq!(compa.shl_digits(3), "1T~PC000");
+    assert_eq!(
+        compa.shl_digits(2).to_biguint().unwrap(),
+        BigUint::from(123456789u64 * 95 * 95)
+    );
+    assert_eq!(CompaDecimal::new().shl_digits(4), "0");
+
+    assert_eq!(compa.shr_digits(0), "1T~PC");
+    assert_eq!(compa.shr_digits(2), "1T~");
+    assert_eq!(compa.shr_digits(5), "0");
+    assert_eq!(compa.shr_digits(10), "0");
+    let compa: CompaDecimal = "00100".parse().unwrap();
+    assert_eq!(compa.shr_digits(2), "1");
+}
+
+#[test]
+fn low_high_digits_test() {
+    let compa: CompaDecimal = "1T~PC".parse().unwrap();
+    assert_eq!(compa.low_digits(2), "PC");
+    assert_eq!(compa.low_digits(0), "0");
+    assert_eq!(compa.low_digits(9), "1T~PC");
+    assert_eq!(
+        compa.low_digits(3).to_biguint().unwrap(),
+        BigUint::from(123456789u64 % (95 * 95 * 95))
+    );
+    let compa: CompaDecimal = "1T00C".parse().unwrap();
+    assert_eq!(compa.low_digits(3), "C");
+
+    let compa: CompaDecimal = "001T~PC".parse().unwrap();
+    assert_eq!(compa.high_digits(2), "1T");
+    assert_eq!(compa.high_digits(0), "0");
+    assert_eq!(compa.high_digits(7), "1T~PC");
+}
+
+#[test]
+fn split_at_digit_test() {
+    let compa: CompaDecimal = "1T~PC".parse().unwrap();
+    let (high, low) = compa.split_at_digit(2);
+    assert_eq!(high, "1T~");
+    assert_eq!(low, "PC");
+    assert_eq!(high.shl_digits(2).add(low.get_value()).unwrap(), compa);
+
+    let (high, low) = compa.split_at_digit(0);
+    assert_eq!(high, "1T~PC");
+    assert_eq!(low, "0");
+    let (high, low) = compa.split_at_digit(8);
+    assert_eq!(high, "0");
+    assert_eq!(low, "1T~PC");
+}
+
+#[test]
+fn digits_test() {
+    let compa: CompaDecimal = "001T~PC".parse().unwrap();
+    let digits: Vec<u8> = compa.digits().collect();
+    assert_eq!(digits, vec![1, 48, 94, 40, 14]);
+    assert_eq!(compa.digit_count(), 5);
+    assert_eq!(CompaDecimal::new().digit_count(), 1);
+    assert_eq!(CompaDecimal::new().digits().collect::<Vec<u8>>(), vec![0]);
+
+    let rebuilt = compa
+        .digits()
+        .fold(BigUint::zero(), |acc, digit| acc * 95u32 + digit);
+    assert_eq!(rebuilt, compa.to_biguint().unwrap());
+}
+
+#[test]
+fn digit_test() {
+    let compa: CompaDecimal = "1T~PC".parse().unwrap();
+    assert_eq!(compa.digit(0), Some(14));
+    assert_eq!(compa.digit(4), Some(1));
+    assert_eq!(compa.digit(5), None);
+    assert_eq!(compa.digit_from_most(0), Some(1));
+    assert_eq!(compa.digit_from_most(4), Some(14));
+    assert_eq!(compa.digit_from_most(5), None);
+}
+
+#[test]
+fn set_digit_test() {
+    let mut compa: CompaDecimal = "1T~PC".parse().unwrap();
+    compa.set_digit(0, 94).unwrap();
+    assert_eq!(compa, "1T~P~");
+    compa.set_digit(6, 1).unwrap();
+    assert_eq!(compa, "101T~P~");
+    compa.set_digit(6, 0).unwrap();
+    assert_eq!(compa, "1T~P~");
+    assert!(compa.set_digit(0, 95).is_err());
+    assert_eq!(compa, "1T~P~");
+}
+
+#[test]
+fn gcd_lcm_test() {
+    let a = CompaDecimal::from(1071u32);
+    let b = CompaDecimal::from(462u32);
+    assert_eq!(a.gcd(&b), CompaDecimal::from(21u32));
+    assert_eq!(a.lcm(&b), CompaDecimal::from(23562u32));
+    assert_eq!(a.gcd(&CompaDecimal::new()), a);
+    assert_eq!(a.lcm(&CompaDecimal::new()), "0");
+}
+
+#[test]
+fn mod_pow_test() {
+    let base = CompaDecimal::from(4u32);
+    let exponent = CompaDecimal::from(13u32);
+    let modulus = CompaDecimal::from(497u32);
+    assert_eq!(
+        base.mod_pow(&exponent, &modulus).unwrap(),
+        CompaDecimal::from(445u32)
+    );
+    assert!(base.mod_pow(&exponent, &CompaDecimal::new()).is_err());
+}
+
+#[test]
+fn mod_inverse_test() {
+    let a = CompaDecimal::from(3u32);
+    let modulus = CompaDecimal::from(11u32);
+    assert_eq!(a.mod_inverse(&modulus).unwrap(), CompaDecimal::from(4u32));
+    assert!(CompaDecimal::from(6u32)
+        .mod_inverse(&CompaDecimal::from(9u32))
+        .is_err());
+    assert!(a.mod_inverse(&CompaDecimal::new()).is_err());
+}
+
+#[test]
+fn isqrt_test() {
+    assert_eq!(CompaDecimal::from(99u32).isqrt(), CompaDecimal::from(9u32));
+    assert_eq!(
+        CompaDecimal::from(100u32).isqrt(),
+        CompaDecimal::from(10u32)
+    );
+    assert_eq!(CompaDecimal::new().isqrt(), "0");
+    let big = CompaDecimal::from(u128::MAX);
+    assert_eq!(big.isqrt(), CompaDecimal::from(u64::MAX));
+}
+
+#[test]
+fn ilog_test() {
+    let base: CompaDecimal = "10".parse().unwrap();
+    assert_eq!(
+        "1T~PC"
+            .parse::<CompaDecimal>()
+            .unwrap()
+            .ilog(&base)
+            .unwrap(),
+        4
+    );
+    assert_eq!(
+        "10000"
+            .parse::<CompaDecimal>()
+            .unwrap()
+            .ilog(&base)
+            .unwrap(),
+        4
+    );
+    assert_eq!(
+        "~~~~".parse::<CompaDecimal>().unwrap().ilog(&base).unwrap(),
+        3
+    );
+    assert_eq!(CompaDecimal::from(1u32).ilog(&base).unwrap(), 0);
+
+    let two = CompaDecimal::from(2u32);
+    assert_eq!(CompaDecimal::from(u128::MAX).ilog(&two).unwrap(), 127);
+    assert_eq!(CompaDecimal::from(1024u32).ilog(&two).unwrap(), 10);
+    assert!(CompaDecimal::new().ilog(&two).is_err());
+    assert!(two.ilog(&CompaDecimal::from(1u32)).is_err());
+}
+
+#[test]
+fn is_probable_prime_test() {
+    let primes: Vec<u64> = (0..200u64)
+        .filter(|&n| n >= 2 && (2..n).all(|d| n % d != 0))
+        .collect();
+    for n in 0..200u64 {
+        assert_eq!(
+            CompaDecimal::from(n).is_probable_prime(),
+            primes.contains(&n),
+            "{}",
+            n
+        );
+    }
+
+    // Strong pseudoprimes to several small bases.
+    assert!(!CompaDecimal::from(3215031751u64).is_probable_prime());
+    assert!(!CompaDecimal::from(3825123056546413051u64).is_probable_prime());
+    assert!(CompaDecimal::from(18446744073709551557u64).is_probable_prime());
+    assert!(!CompaDecimal::from(18446744073709551559u64).is_probable_prime());
+
+    // 2^127 - 1 is a Mersenne prime, 2^128 + 1 is not prime.
+    let mersenne = CompaDecimal::from(u128::MAX >> 1);
+    assert!(mersenne.is_probable_prime());
+    assert!(mersenne.is_probable_prime_with_rounds(40));
+    let composite = &CompaDecimal::from(u128::MAX) + &CompaDecimal::from(2u32);
+    assert!(!composite.is_probable_prime());
+    let semiprime = &mersenne * &CompaDecimal::from(18446744073709551557u64);
+    assert!(!semiprime.is_probable_prime_with_rounds(1));
+    assert!(!semiprime.is_probable_prime_with_rounds(0));
+    assert!(mersenne.is_probable_prime_with_rounds(0));
+}
+
+#[test]
+fn from_str_radix_test() {
+    assert_eq!(
+        CompaDecimal::from_str_radix("75aBb15", 16).unwrap(),
+        "1T~PC"
+    );
+    assert_eq!(CompaDecimal::from_str_radix("21E3k9", 36).unwrap(), "1T~PC");
+    assert_eq!(
+        CompaDecimal::from_str_radix("111010110111100110100010101", 2).unwrap(),
+        "1T~PC"
+    );
+    assert_eq!(CompaDecimal::from_str_radix("1T~PC", 95).unwrap(), "1T~PC");
+    assert_eq!(
+        CompaDecimal::from_str_radix("0001T~PC", 95).unwrap(),
+        "1T~PC"
+    );
+    assert_eq!(
+        CompaDecimal::from_str_radix("10", 37).unwrap(),
+        CompaDecimal::from(37u32)
+    );
+
+    // One alphabet for every radix: 'a' is digit 11 whether the radix is 16, 36 or 37.
+    for radix in [16, 36, 37] {
+        assert_eq!(
+            CompaDecimal::from_str_radix("a", radix).unwrap(),
+            CompaDecimal::from(11u32)
+        );
+    }
+    assert!(CompaDecimal::from_str_radix("a", 11).is_err());
+    assert_eq!(
+        CompaDecimal::from_str_radix_std("a", 16).unwrap(),
+        CompaDecimal::from(10u32)
+    );
+
+    let error = CompaDecimal::from_str_radix("75BG15", 16).unwrap_err();
+    assert_eq!(
+        error.error_message,
+        "Invalid compa digit 'G' at byte 3 for radix 16"
+    );
+    let error = CompaDecimal::from_str_radix("1£2", 10).unwrap_err();
+    assert_eq!(
+        error.error_message,
+        "Invalid compa digit '£' at byte 1 for radix 10"
+    );
+    assert!(CompaDecimal::from_str_radix("~", 94).is_err());
+    assert!(CompaDecimal::from_str_radix("1", 1).is_err());
+    assert!(CompaDecimal::from_str_radix("1", 96).is_err());
+    assert_eq!(
+        CompaDecimal::from_str_radix("", 10)
+            .unwrap_err()
+            .error_message,
+        "Cannot parse an empty string"
+    );
+}
+
+#[test]
+fn to_string_radix_test() {
+    let compa: CompaDecimal = "1T~PC".parse().unwrap();
+    assert_eq!(compa.to_string_radix(16).unwrap(), "75aBb15");
+    assert_eq!(compa.to_string_radix(36).unwrap(), "21E3k9");
+    assert_eq!(compa.to_string_radix(10).unwrap(), "123456789");
+    assert_eq!(compa.to_string_radix(95).unwrap(), "1T~PC");
+    assert_eq!(CompaDecimal::new().to_string_radix(16).unwrap(), "0");
+    assert!(compa.to_string_radix(1).is_err());
+    assert!(compa.to_string_radix(96).is_err());
+
+    for radix in 2..=95 {
+        let text = compa.to_string_radix(radix).unwrap();
+        assert_eq!(CompaDecimal::from_str_radix(&text, radix).unwrap(), compa);
+    }
+}
+
+#[test]
+fn std_radix_test() {
+    let compa: CompaDecimal = "1T~PC".parse().unwrap();
+    assert_eq!(
+        CompaDecimal::from_str_radix_std("75bcd15", 16).unwrap(),
+        compa
+    );
+    assert_eq!(
+        CompaDecimal::from_str_radix_std("75BCD15", 16).unwrap(),
+        compa
+    );
+    assert_eq!(
+        CompaDecimal::from_str_radix_std("21i3v9", 36).unwrap(),
+        compa
+    );
+    assert_eq!(compa.to_string_radix_std(16).unwrap(), "75bcd15");
+    assert_eq!(compa.to_string_radix_std(36).unwrap(), "21i3v9");
+    assert!(compa.to_string_radix_std(37).is_err());
+    assert!(CompaDecimal::from_str_radix_std("10", 37).is_err());
+    assert_eq!(
+        CompaDecimal::from_str_radix_std("75bg15", 16)
+            .unwrap_err()
+            .error_message,
+        "Invalid compa digit 'g' at byte 3 for radix 16"
+    );
+
+    for radix in 2..=36 {
+        let text = compa.to_string_radix_std(radix).unwrap();
+        assert_eq!(
+            CompaDecimal::from_str_radix_std(&text, radix).unwrap(),
+            compa
+        );
+    }
+}
+
+#[test]
+fn decimal_str_test() {
+    let compa = CompaDecimal::from_decimal_str("340282366920938463463374607431768211455").unwrap();
+    assert_eq!(compa, "91\"<n.hl48T!YkTkA?1Z");
+    assert_eq!(
+        compa.to_decimal_string(),
+        "340282366920938463463374607431768211455"
+    );
+    assert_eq!(CompaDecimal::new().to_decimal_string(), "0");
+    let error = CompaDecimal::from_decimal_str("12a4").unwrap_err();
+    assert_eq!(
+        error.error_message,
+        "Invalid compa digit 'a' at byte 2 for radix 10"
+    );
+}
+
+#[test]
+fn display_padding_test() {
+    let compa: CompaDecimal = "1T~PC".parse().unwrap();
+    assert_eq!(format!("{}", compa), "1T~PC");
+    assert_eq!(format!("{:>8}", compa), "   1T~PC");
+    assert_eq!(format!("{:<8}|", compa), "1T~PC   |");
+    assert_eq!(format!("{:^9}", compa), "  1T~PC  ");
+    assert_eq!(format!("{:*>8}", compa), "***1T~PC");
+    assert_eq!(format!("{:>3}", compa), "1T~PC");
+    assert_eq!(format!("{:>width$}", compa, width = 6), " 1T~PC");
+}
+
+#[test]
+fn display_alternate_test() {
+    let compa: CompaDecimal = "1T~PC".parse().unwrap();
+    assert_eq!(format!("{:#}", compa), "0c1T~PC");
+    assert_eq!(format!("{:>#9}", compa), "  0c1T~PC");
+    assert_eq!(ALTERNATE_PREFIX, "0c");
+}
+
+#[test]
+fn alternate_form_round_trip_test() {
+    let compa: CompaDecimal = "1T~PC".parse().unwrap();
+    let alternate = format!("{:#}", compa);
+    assert_eq!(CompaDecimal::from_alternate(&alternate).unwrap(), compa);
+    let padded = format!("{:#09}", compa);
+    assert_eq!(
+        CompaDecimal::from_alternate(&padded)
+            .unwrap()
+            .cmp_str("1T~PC"),
+        Ok(Ordering::Equal)
+    );
+
+    assert!(CompaDecimal::from_alternate("1T~PC").is_err());
+    assert!(CompaDecimal::from_alternate("0c").is_err());
+    assert_eq!(
+        CompaDecimal::from_alternate("0c1T£")
+            .unwrap_err()
+            .error_message,
+        "Invalid compa digit '£' at byte 4"
+    );
+}
+
+#[test]
+fn prefix_digits_are_kept_test() {
+    // "0c" is also two ordinary digits, so every parser other than from_alternate
+    // keeps them.
+    let compa: CompaDecimal = "0c5".parse().unwrap();
+    assert_eq!(compa, "0c5");
+    assert_eq!(compa.to_decimal::<u64>().unwrap(), 1430);
+    assert_eq!(CompaDecimal::try_from("0c5").unwrap(), "0c5");
+    assert_eq!(CompaU64::parse("0c5").unwrap().get(), 1430);
+    assert_eq!(CompaU64::decode(b"0c5").unwrap().get(), 1430);
+    assert_eq!(compa!("0c5"), "c5");
+    assert_eq!(compa.cmp_str("c5"), Ok(Ordering::Equal));
+
+    let short: CompaDecimal = "c1".parse().unwrap();
+    let padded: CompaDecimal = format!("{:03}", short).parse().unwrap();
+    assert_eq!(padded.cmp_str("c1"), Ok(Ordering::Equal));
+}
+
+#[test]
+fn display_zero_padding_test() {
+    let compa: CompaDecimal = "1T~PC".parse().unwrap();
+    assert_eq!(format!("{:08}", compa), "0001T~PC");
+    assert_eq!(format!("{:03}", compa), "1T~PC");
+    assert_eq!(format!("{:#09}", compa), "0c001T~PC");
+    let padded: CompaDecimal = format!("{:010}", compa).parse().unwrap();
+    assert_eq!(padded.to_biguint().unwrap(), compa.to_biguint().unwrap());
+}
+
+#[test]
+fn fixed_display_formatting_test() {
+    let compa = CompaU64::new(123456789);
+    assert_eq!(format!("{:>8}", compa), "   1T~PC");
+    assert_eq!(format!("{:010}", compa), "000001T~PC");
+    assert_eq!(format!("{:#}", compa.encode()), "0c1T~PC");
+    assert_eq!(format!("{:<6}|", CompaU128::new(0)), "0     |");
+}
+
+#[test]
+fn to_grouped_test() {
+    let compa: CompaDecimal = "91\"<n.hl48T!YkTkA?1Z".parse().unwrap();
+    assert_eq!(
+        compa.to_grouped(5, '·').unwrap(),
+        "91\"<n·.hl48·T!YkT·kA?1Z"
+    );
+    let compa: CompaDecimal = "1T~PC".parse().unwrap();
+    assert_eq!(
+        compa.to_grouped(2, '\u{2009}').unwrap(),
+        "1\u{2009}T~\u{2009}PC"
+    );
+    assert_eq!(compa.to_grouped(5, '·').unwrap(), "1T~PC");
+    assert_eq!(compa.to_grouped(1, '·').unwrap(), "1·T·~·P·C");
+
+    assert!(compa.to_grouped(0, '·').is_err());
+    let error = compa.to_grouped(2, '-').unwrap_err();
+    assert_eq!(
+        error.error_message,
+        "Separator '-' is a compa digit and cannot be used to group digits"
+    );
+    assert!(compa.to_grouped(2, ' ').is_err());
+    assert!(compa.to_grouped(2, '.').is_err());
+}
+
+#[test]
+fn from_grouped_test() {
+    let compa = CompaDecimal::from_grouped("91\"<n·.hl48·T!YkT·kA?1Z", '·').unwrap();
+    assert_eq!(compa, "91\"<n.hl48T!YkTkA?1Z");
+    assert_eq!(CompaDecimal::from_grouped("1·T~·PC", '·').unwrap(), "1T~PC");
+    assert_eq!(CompaDecimal::from_grouped("1T~PC", '·').unwrap(), "1T~PC");
+    assert_eq!(
+        CompaDecimal::from_grouped("1T·~P··C·", '·').unwrap(),
+        "1T~PC"
+    );
+
+    assert!(CompaDecimal::from_grouped("1-T~-PC", '-').is_err());
+    assert!(CompaDecimal::from_grouped("1·T~|PC£", '·').is_err());
+    assert!(CompaDecimal::from_grouped("··", '·').is_err());
+
+    let compa: CompaDecimal = "AsdgrW11".parse().unwrap();
+    let grouped = compa.to_grouped(3, '\t').unwrap();
+    assert_eq!(grouped, "As\tdgr\tW11");
+    assert_eq!(CompaDecimal::from_grouped(&grouped, '\t').unwrap(), compa);
+}
+
+#[test]
+fn max_min_with_len_test() {
+    assert_eq!(
+        CompaDecimal::max_with_len(0).unwrap_err().error_message,
+        "Length has to be at least one digit"
+    );
+    assert_eq!(CompaDecimal::max_with_len(1).unwrap(), "~");
+    assert_eq!(CompaDecimal::max_with_len(3).unwrap(), "~~~");
+    assert_eq!(
+        CompaDecimal::max_with_len(3).unwrap().to_biguint().unwrap(),
+        BigUint::from(95u32.pow(3) - 1)
+    );
+    assert!(CompaDecimal::min_with_len(0).is_err());
+    assert_eq!(CompaDecimal::min_with_len(1).unwrap(), "0");
+    assert_eq!(CompaDecimal::min_with_len(4).unwrap(), "1000");
+    assert_eq!(
+        CompaDecimal::min_with_len(4).unwrap().to_biguint().unwrap(),
+        BigUint::from(95u32.pow(3))
+    );
+}
+
+#[test]
+fn digits_needed_test() {
+    assert_eq!(CompaDecimal::digits_needed::<u8>(), 2);
+    assert_eq!(CompaDecimal::digits_needed::<u16>(), 3);
+    assert_eq!(CompaDecimal::digits_needed::<u32>(), 5);
+    assert_eq!(CompaDecimal::digits_needed::<u64>(), 10);
+    assert_eq!(CompaDecimal::digits_needed::<u128>(), 20);
+    assert_eq!(CompaDecimal::digits_needed::<u64>(), CompaU64::MAX_LEN);
+    assert_eq!(CompaDecimal::digits_needed::<u128>(), CompaU128::MAX_LEN);
+    assert_eq!(
+        CompaDecimal::digits_needed::<u128>(),
+        CompaDecimal::from(u128::MAX).len()
+    );
+}
+
+#[test]
+fn digits_needed_for_test() {
+    assert_eq!(CompaDecimal::digits_needed_for(&BigUint::zero()), 1);
+    assert_eq!(CompaDecimal::digits_needed_for(&BigUint::from(94u32)), 1);
+    assert_eq!(CompaDecimal::digits_needed_for(&BigUint::from(95u32)), 2);
+    assert_eq!(
+        CompaDecimal::digits_needed_for(&BigUint::from(123456789u32)),
+        5
+    );
+    let big = BigUint::from(95u32).pow(100);
+    assert_eq!(CompaDecimal::digits_needed_for(&big), 101);
+    assert_eq!(CompaDecimal::digits_needed_for(&(big - 1u32)), 100);
+}
+
+#[test]
+fn compression_ratio_vs_decimal_test() {
+    let ratio = CompaDecimal::compression_ratio_vs_decimal();
+    assert!((ratio - 0.50565).abs() < 0.0001);
+    let decimal_len = u128::MAX.to_string().len() as f64;
+    assert_eq!(
+        (decimal_len * ratio).ceil() as usize,
+        CompaDecimal::digits_needed::<u128>()
+    );
+}
+
+#[test]
+fn to_bytes_test() {
+    let compa: CompaDecimal = "1T~PC".parse().unwrap();
+    assert_eq!(compa.to_bytes(), vec![4, 0x07, 0x5B, 0xCD, 0x15]);
+    assert_eq!(CompaDecimal::new().to_bytes(), vec![0]);
+    assert_eq!("000".parse::<CompaDecimal>().unwrap().to_bytes(), vec![0]);
+
+    let big = CompaDecimal::from(BigUint::one() << 1599u32);
+    let bytes = big.to_bytes();
+    assert_eq!(&bytes[..3], &[0xC8, 0x01, 0x80]);
+    assert_eq!(bytes.len(), 202);
+    assert!(bytes.len() < big.len());
+}
+
+#[test]
+fn from_bytes_test() {
+    for value in [
+        "0",
+        "1",
+        "~",
+        "1T~PC",
+        "91\"<n.hl48T!YkTkA?1Z",
+        "AsdgrW11 ~\\",
+    ] {
+        let compa: CompaDecimal = value.parse().unwrap();
+        assert_eq!(CompaDecimal::from_bytes(&compa.to_bytes()).unwrap(), compa);
+    }
+    let big = CompaDecimal::from(BigUint::one() << 1599u32);
+    assert_eq!(CompaDecimal::from_bytes(&big.to_bytes()).unwrap(), big);
+
+    assert_eq!(
+        CompaDecimal::from_bytes(&[4, 0x07, 0x5B, 0xCD, 0x15])
+            .unwrap()
+            .get_value(),
+        "1T~PC"
+    );
+    assert!(CompaDecimal::from_bytes(&[]).is_err());
+    assert!(CompaDecimal::from_bytes(&[4, 0x07, 0x5B]).is_err());
+    assert!(CompaDecimal::from_bytes(&[1, 5, 0]).is_err());
+    assert!(CompaDecimal::from_bytes(&[2, 0, 5]).is_err());
+    assert!(CompaDecimal::from_bytes(&[0x81, 0x00, 5]).is_err());
+    assert!(CompaDecimal::from_bytes(&[0xFF; 11]).is_err());
+}
+
+#[test]
+fn read_from_test() {
+    let a: CompaDecimal = "1T~PC".parse().unwrap();
+    let b: CompaDecimal = "~".parse().unwrap();
+    let mut buffer = Vec::new();
+    a.write_to(&mut buffer).unwrap();
+    b.write_to(&mut buffer).unwrap();
+    CompaDecimal::new().write_to(&mut buffer).unwrap();
+
+    let mut reader = buffer.as_slice();
+    assert_eq!(CompaDecimal::read_from(&mut reader).unwrap(), a);
+    assert_eq!(CompaDecimal::read_from(&mut reader).unwrap(), b);
+    assert!(CompaDecimal::read_from(&mut reader).unwrap().is_zero());
+    assert_eq!(
+        CompaDecimal::read_from(&mut reader)
+            .unwrap_err()
+            .error_message,
+        "Unexpected end of input in a compa value"
+    );
+
+    // A corrupt length larger than the input fails without allocating it up front.
+    let mut corrupt: &[u8] = &[0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 1];
+    assert!(CompaDecimal::read_from(&mut corrupt).is_err());
 }
@@ -0,0 +1,67 @@
+#![cfg(any(feature = "bincode", feature = "borsh", feature = "serde"))]
+
+use compa_decimal::*;
+
+fn samples() -> Vec<CompaDecimal> {
+    ["0", "1T~PC", "91\"<n.hl48T!YkTkA?1Z", "AsdgrW11 ~\\"]
+        .iter()
+        .map(|value| value.parse().unwrap())
+        .collect()
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn bincode_test() {
+    let config = bincode::config::standard();
+    for compa in samples() {
+        let bytes = bincode::encode_to_vec(&compa, config).unwrap();
+        assert_eq!(bytes, compa.to_bytes());
+        let (decoded, read): (CompaDecimal, usize) =
+            bincode::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(decoded, compa);
+        assert_eq!(read, bytes.len());
+    }
+    let pair = ("1T~PC".parse::<CompaDecimal>().unwrap(), 7u8);
+    let bytes = bincode::encode_to_vec(&pair, config).unwrap();
+    let (decoded, _): ((CompaDecimal, u8), usize) =
+        bincode::decode_from_slice(&bytes, config).unwrap();
+    assert_eq!(decoded, pair);
+    assert!(bincode::decode_from_slice::<CompaDecimal, _>(&[1, 0], config).is_err());
+}
+
+#[cfg(feature = "borsh")]
+#[test]
+fn borsh_test() {
+    for compa in samples() {
+        let bytes = borsh::to_vec(&compa).unwrap();
+        assert_eq!(bytes, compa.to_bytes());
+        assert_eq!(borsh::from_slice::<CompaDecimal>(&bytes).unwrap(), compa);
+    }
+    assert!(borsh::from_slice::<CompaDecimal>(&[2, 0, 1]).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn postcard_test() {
+    for compa in samples() {
+        let bytes = postcard::to_allocvec(&compa).unwrap();
+        assert_eq!(bytes, compa.to_bytes());
+        assert_eq!(postcard::from_bytes::<CompaDecimal>(&bytes).unwrap(), compa);
+    }
+    // Inside a larger message the length prefix has to end each value exactly.
+    let message = (samples(), "1T~PC".parse::<CompaDecimal>().unwrap(), 7u8);
+    let bytes = postcard::to_allocvec(&message).unwrap();
+    let decoded: (Vec<CompaDecimal>, CompaDecimal, u8) = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded, message);
+    assert!(postcard::from_bytes::<CompaDecimal>(&[1, 0]).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_json_test() {
+    let compa: CompaDecimal = "1T~PC".parse().unwrap();
+    let json = serde_json::to_string(&compa).unwrap();
+    assert_eq!(json, "\"1T~PC\"");
+    assert_eq!(serde_json::from_str::<CompaDecimal>(&json).unwrap(), compa);
+    assert!(serde_json::from_str::<CompaDecimal>("\"1£\"").is_err());
+}
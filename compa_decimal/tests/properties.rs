@@ -0,0 +1,133 @@
+#![cfg(any(feature = "proptest", feature = "quickcheck", feature = "arbitrary"))]
+
+use compa_decimal::*;
+use num::BigUint;
+
+fn biguint(compa: &CompaDecimal) -> BigUint {
+    compa.to_biguint().unwrap()
+}
+
+#[cfg(feature = "proptest")]
+mod proptests {
+    use super::*;
+    use compa_decimal::testing::{any_compa, compa_up_to_max, compa_with_len};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn to_biguint_round_trip(compa in any_compa()) {
+            let num = biguint(&compa);
+            let back = CompaDecimal::biguint_to_compa(&num).unwrap();
+            prop_assert_eq!(biguint(&back), num);
+            let trimmed = compa.get_value().trim_start_matches('0');
+            prop_assert_eq!(back.get_value(), if trimmed.is_empty() { "0" } else { trimmed });
+        }
+
+        #[test]
+        fn add_matches_biguint(a in any_compa(), b in any::<CompaDecimal>()) {
+            let sum = a.add(b.get_value()).unwrap();
+            prop_assert_eq!(biguint(&sum), biguint(&a) + biguint(&b));
+        }
+
+        #[test]
+        fn subtract_matches_biguint(a in any_compa(), b in any_compa()) {
+            let (big_a, big_b) = (biguint(&a), biguint(&b));
+            match a.subtract(b.get_value()) {
+                Ok(difference) => {
+                    prop_assert!(big_a >= big_b);
+                    prop_assert_eq!(biguint(&difference), big_a - big_b);
+                }
+                Err(_) => prop_assert!(big_a < big_b),
+            }
+        }
+
+        #[test]
+        fn add_then_subtract(a in any_compa(), b in any_compa()) {
+            let sum = a.add(b.get_value()).unwrap();
+            let difference = sum.subtract(b.get_value()).unwrap();
+            prop_assert_eq!(biguint(&difference), biguint(&a));
+        }
+
+        #[test]
+        fn bounded_by_type_max(compa in compa_up_to_max::<u64>()) {
+            let num = compa.to_decimal::<u64>().unwrap();
+            prop_assert_eq!(BigUint::from(num), biguint(&compa));
+            prop_assert!(compa.len() <= CompaDecimal::digits_needed::<u64>());
+        }
+
+        #[test]
+        fn with_len_has_len_digits(
+            (len, compa) in (1usize..30).prop_flat_map(|len| (Just(len), compa_with_len(len)))
+        ) {
+            prop_assert_eq!(compa.len(), len);
+            prop_assert_eq!(CompaDecimal::digits_needed_for(&biguint(&compa)), len);
+        }
+    }
+}
+
+// proptest once shrank a failure down to this single symbol digit, so it stays pinned
+// here rather than in a regressions file.
+#[test]
+fn single_symbol_digit_test() {
+    let compa: CompaDecimal = "!".parse().unwrap();
+    let num = biguint(&compa);
+    assert_eq!(CompaDecimal::biguint_to_compa(&num).unwrap(), compa);
+    assert_eq!(biguint(&compa.add("!").unwrap()), &num + &num);
+    assert_eq!(compa.subtract("!").unwrap(), "0");
+}
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use super::*;
+    use quickcheck::{quickcheck, Arbitrary, Gen};
+
+    quickcheck! {
+        fn add_matches_biguint(a: CompaDecimal, b: CompaDecimal) -> bool {
+            biguint(&a.add(b.get_value()).unwrap()) == biguint(&a) + biguint(&b)
+        }
+
+        fn subtract_matches_biguint(a: CompaDecimal, b: CompaDecimal) -> bool {
+            match a.subtract(b.get_value()) {
+                Ok(difference) => biguint(&difference) + biguint(&b) == biguint(&a),
+                Err(_) => biguint(&a) < biguint(&b),
+            }
+        }
+    }
+
+    #[test]
+    fn shrink_test() {
+        let compa: CompaDecimal = "00 \\".parse().unwrap();
+        let shrunk: Vec<String> = compa
+            .shrink()
+            .map(|compa| compa.get_value().to_string())
+            .collect();
+        assert_eq!(shrunk, vec![" \\", "0 \\", "00 "]);
+
+        let mut gen = Gen::new(100);
+        for _ in 0..100 {
+            let compa = CompaDecimal::arbitrary(&mut gen);
+            assert!(!compa.is_empty() && compa.to_biguint().is_ok());
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_test() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let data: Vec<u8> = (0..=255u8).cycle().take(4096).collect();
+    let mut unstructured = Unstructured::new(&data);
+    let mut seen = [false; 95];
+    while !unstructured.is_empty() {
+        let compa = CompaDecimal::arbitrary(&mut unstructured).unwrap();
+        for digit in compa.get_value().chars() {
+            seen[digit_value(digit).unwrap() as usize] = true;
+        }
+    }
+    assert!(seen.iter().all(|&x| x));
+    assert_eq!(
+        CompaDecimal::arbitrary(&mut Unstructured::new(&[])).unwrap(),
+        "0"
+    );
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "rand")]
+
+use compa_decimal::*;
+use num::BigUint;
+use rand::{distr::Distribution, rngs::StdRng, SeedableRng};
+
+#[test]
+fn random_below_test() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let bound: CompaDecimal = "1~".parse().unwrap();
+    let mut seen = [false; 189];
+    for _ in 0..5000 {
+        let compa = CompaDecimal::random_below(&bound, &mut rng).unwrap();
+        assert!(compa < bound);
+        seen[compa.to_decimal::<usize>().unwrap()] = true;
+    }
+    assert!(seen.iter().all(|&x| x));
+
+    let zero = CompaDecimal::new();
+    assert!(CompaDecimal::random_below(&zero, &mut rng).is_err());
+}
+
+#[test]
+fn random_in_range_test() {
+    let mut rng = StdRng::seed_from_u64(11);
+    let low: CompaDecimal = "AsdgrW11".parse().unwrap();
+    let high: CompaDecimal = "AsdgrW1A".parse().unwrap();
+    for _ in 0..500 {
+        let compa = CompaDecimal::random_in_range(low.clone()..high.clone(), &mut rng).unwrap();
+        assert!(compa >= low && compa < high);
+        let compa = CompaDecimal::random_in_range(low.clone()..=high.clone(), &mut rng).unwrap();
+        assert!(compa >= low && compa <= high);
+    }
+
+    let compa = CompaDecimal::random_in_range(low.clone()..=low.clone(), &mut rng).unwrap();
+    assert_eq!(compa, "AsdgrW11");
+    assert!(CompaDecimal::random_in_range(high.clone()..low.clone(), &mut rng).is_err());
+    assert!(CompaDecimal::random_in_range(low.clone().., &mut rng).is_err());
+}
+
+#[test]
+fn random_with_len_test() {
+    let mut rng = StdRng::seed_from_u64(13);
+    for len in 1..40 {
+        let compa = CompaDecimal::random_with_len(len, &mut rng).unwrap();
+        assert_eq!(compa.len(), len);
+    }
+    assert!(CompaDecimal::random_with_len(0, &mut rng).is_err());
+    #[cfg(target_pointer_width = "64")]
+    assert!(CompaDecimal::random_with_len(u32::MAX as usize + 1, &mut rng).is_err());
+}
+
+#[test]
+fn compa_uniform_test() {
+    let rng = StdRng::seed_from_u64(17);
+    let distribution = CompaUniform::with_len(3).unwrap();
+    let max = BigUint::from(95u32).pow(3);
+    for compa in distribution.sample_iter(rng).take(500) {
+        assert_eq!(compa.len(), 3);
+        assert!(compa.to_biguint().unwrap() < max);
+    }
+}
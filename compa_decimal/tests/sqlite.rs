@@ -0,0 +1,133 @@
+#![cfg(feature = "rusqlite")]
+
+use compa_decimal::*;
+use rusqlite::{params, Connection};
+
+fn connection() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    sqlite::register(&conn).unwrap();
+    conn
+}
+
+#[test]
+fn to_sql_from_sql_test() {
+    let conn = connection();
+    conn.execute("CREATE TABLE ids (id TEXT)", []).unwrap();
+    let compa: CompaDecimal = "001T~PC".parse().unwrap();
+    conn.execute("INSERT INTO ids VALUES (?1)", params![compa])
+        .unwrap();
+
+    let stored: String = conn
+        .query_row("SELECT id FROM ids", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(stored, "1T~PC");
+    let read: CompaDecimal = conn
+        .query_row("SELECT id FROM ids", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(read, "1T~PC");
+
+    let from_integer: CompaDecimal = conn
+        .query_row("SELECT 123456789", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(from_integer, "1T~PC");
+    let from_blob: CompaDecimal = conn
+        .query_row("SELECT ?1", [compa.to_bytes()], |row| row.get(0))
+        .unwrap();
+    assert_eq!(from_blob, "1T~PC");
+
+    assert!(conn
+        .query_row("SELECT -1", [], |row| row.get::<_, CompaDecimal>(0))
+        .is_err());
+    assert!(conn
+        .query_row("SELECT '1£'", [], |row| row.get::<_, CompaDecimal>(0))
+        .is_err());
+}
+
+#[test]
+fn collation_test() {
+    let conn = connection();
+    conn.execute("CREATE TABLE ids (id TEXT)", []).unwrap();
+    // "0c5" is 1430. The prefix of `{:#}` output is ordinary digits here.
+    for id in ["a", "0c5", "10", "~", "B", "0A", "not compa£", "1 "] {
+        conn.execute("INSERT INTO ids VALUES (?1)", [id]).unwrap();
+    }
+
+    let ordered = |sql: &str| -> Vec<String> {
+        let mut statement = conn.prepare(sql).unwrap();
+        statement
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<Vec<String>, _>>()
+            .unwrap()
+    };
+    assert_eq!(
+        ordered("SELECT id FROM ids ORDER BY id COLLATE COMPA"),
+        vec!["0A", "a", "B", "~", "10", "1 ", "0c5", "not compa£"]
+    );
+    assert_ne!(
+        ordered("SELECT id FROM ids ORDER BY id"),
+        ordered("SELECT id FROM ids ORDER BY id COLLATE COMPA")
+    );
+
+    let max: String = conn
+        .query_row(
+            "SELECT id FROM ids WHERE id <> 'not compa£' ORDER BY id COLLATE COMPA DESC LIMIT 1",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(max, "0c5");
+    assert_eq!(sqlite::compare("0001", "1"), std::cmp::Ordering::Equal);
+    assert_eq!(sqlite::compare("0c5", "c5"), std::cmp::Ordering::Equal);
+    assert_eq!(sqlite::compare("0c5", "5"), std::cmp::Ordering::Greater);
+
+    let read: CompaDecimal = conn
+        .query_row("SELECT id FROM ids WHERE id = '0c5'", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(read, "0c5");
+}
+
+#[test]
+fn functions_test() {
+    let conn = connection();
+    let encoded: String = conn
+        .query_row("SELECT compa_encode(123456789)", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(encoded, "1T~PC");
+    let encoded: String = conn
+        .query_row(
+            "SELECT compa_encode('340282366920938463463374607431768211455')",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(encoded, CompaDecimal::from(u128::MAX).to_string());
+
+    let decoded: i64 = conn
+        .query_row("SELECT compa_decode('1T~PC')", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(decoded, 123456789);
+    let decoded: i64 = conn
+        .query_row("SELECT compa_decode('0c5')", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(decoded, 1430);
+    let decoded: String = conn
+        .query_row(
+            "SELECT compa_decode(?1)",
+            [CompaDecimal::from(u128::MAX)],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(decoded, u128::MAX.to_string());
+
+    let null: Option<String> = conn
+        .query_row("SELECT compa_encode(NULL)", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(null, None);
+    assert!(conn
+        .query_row("SELECT compa_encode(-5)", [], |row| row.get::<_, String>(0))
+        .is_err());
+    assert!(conn
+        .query_row("SELECT compa_decode('1£')", [], |row| row.get::<_, i64>(0))
+        .is_err());
+}
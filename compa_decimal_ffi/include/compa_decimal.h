@@ -0,0 +1,98 @@
+#ifndef COMPA_DECIMAL_H
+#define COMPA_DECIMAL_H
+
+/* Generated by cbindgen from compa_decimal_ffi. Regenerate with UPDATE_HEADER=1 cargo test -p compa_decimal_ffi. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define COMPA_OK 0
+
+#define COMPA_ERR_NULL_POINTER 1
+
+#define COMPA_ERR_INVALID_INPUT 2
+
+#define COMPA_ERR_OVERFLOW 3
+
+#define COMPA_ERR_BUFFER_TOO_SMALL 4
+
+#define COMPA_ERR_PANIC 5
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Encodes `value` into `out` as a NUL-terminated compa string.
+ *
+ * # Safety
+ * `out` must point to `out_len` writable bytes. `written` may be null.
+ */
+int32_t compa_encode_u64(uint64_t value, char *out, size_t out_len, size_t *written);
+
+/**
+ * Decodes the NUL-terminated compa string `input` into `out`.
+ *
+ * # Safety
+ * `input` must be a NUL-terminated string and `out` must be writable.
+ */
+int32_t compa_decode_u64(const char *input, uint64_t *out);
+
+/**
+ * Encodes `value` into `out` as a NUL-terminated compa string.
+ *
+ * # Safety
+ * `out` must point to `out_len` writable bytes. `written` may be null.
+ */
+int32_t compa_encode_u128(unsigned __int128 value, char *out, size_t out_len, size_t *written);
+
+/**
+ * Decodes the NUL-terminated compa string `input` into `out`.
+ *
+ * # Safety
+ * `input` must be a NUL-terminated string and `out` must be writable.
+ */
+int32_t compa_decode_u128(const char *input, unsigned __int128 *out);
+
+/**
+ * Encodes a decimal string of any length into `out` as a NUL-terminated compa string.
+ *
+ * # Safety
+ * `decimal` must be a NUL-terminated string and `out` must point to `out_len`
+ * writable bytes. `written` may be null.
+ */
+int32_t compa_encode_decimal(const char *decimal, char *out, size_t out_len, size_t *written);
+
+/**
+ * Adds two compa strings and writes the NUL-terminated sum into `out`.
+ *
+ * # Safety
+ * `a` and `b` must be NUL-terminated strings and `out` must point to `out_len`
+ * writable bytes. `written` may be null.
+ */
+int32_t compa_add(const char *a, const char *b, char *out, size_t out_len, size_t *written);
+
+/**
+ * Compares two compa strings by value and writes -1, 0 or 1 into `result`.
+ *
+ * # Safety
+ * `a` and `b` must be NUL-terminated strings and `result` must be writable.
+ */
+int32_t compa_cmp(const char *a, const char *b, int32_t *result);
+
+/**
+ * Copies the message of the last failure on this thread into `out`.
+ *
+ * # Safety
+ * `out` must point to `out_len` writable bytes. `written` may be null.
+ */
+int32_t compa_last_error(char *out, size_t out_len, size_t *written);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* COMPA_DECIMAL_H */
@@ -0,0 +1,267 @@
+use std::{
+    cell::RefCell,
+    cmp::Ordering,
+    ffi::{c_char, CStr},
+    panic::{self, AssertUnwindSafe},
+};
+
+use compa_decimal::{CompaDecimal, CompaDecimalError};
+
+pub const COMPA_OK: i32 = 0;
+pub const COMPA_ERR_NULL_POINTER: i32 = 1;
+pub const COMPA_ERR_INVALID_INPUT: i32 = 2;
+pub const COMPA_ERR_OVERFLOW: i32 = 3;
+pub const COMPA_ERR_BUFFER_TOO_SMALL: i32 = 4;
+pub const COMPA_ERR_PANIC: i32 = 5;
+
+thread_local! {
+    static LAST_ERROR: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+struct Error {
+    code: i32,
+    message: String,
+}
+
+impl Error {
+    fn null_pointer() -> Error {
+        Error {
+            code: COMPA_ERR_NULL_POINTER,
+            message: "A required pointer argument is null".to_string(),
+        }
+    }
+
+    fn invalid_input(error: CompaDecimalError) -> Error {
+        Error {
+            code: COMPA_ERR_INVALID_INPUT,
+            message: error.error_message,
+        }
+    }
+
+    fn overflow(error: CompaDecimalError) -> Error {
+        Error {
+            code: COMPA_ERR_OVERFLOW,
+            message: error.error_message,
+        }
+    }
+
+    fn panic(payload: Box<dyn std::any::Any + Send>) -> Error {
+        let reason = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "unknown cause".to_string(),
+            },
+        };
+        Error {
+            code: COMPA_ERR_PANIC,
+            message: format!("Internal error: {}", reason),
+        }
+    }
+}
+
+// Every exported function runs its body through here, so the status code and the
+// message behind `compa_last_error` always agree. Unwinding into C is undefined
+// behavior, so a panic is caught and reported as COMPA_ERR_PANIC instead. The body
+// only touches its own arguments, which is why asserting unwind safety is fine.
+fn status(body: impl FnOnce() -> Result<(), Error>) -> i32 {
+    match panic::catch_unwind(AssertUnwindSafe(body))
+        .unwrap_or_else(|payload| Err(Error::panic(payload)))
+    {
+        Ok(()) => COMPA_OK,
+        Err(error) => {
+            LAST_ERROR.with(|last| *last.borrow_mut() = error.message);
+            error.code
+        }
+    }
+}
+
+unsafe fn read_str<'a>(input: *const c_char) -> Result<&'a str, Error> {
+    if input.is_null() {
+        return Err(Error::null_pointer());
+    }
+    CStr::from_ptr(input).to_str().map_err(|_| Error {
+        code: COMPA_ERR_INVALID_INPUT,
+        message: "Input is not valid UTF-8".to_string(),
+    })
+}
+
+unsafe fn read_compa(input: *const c_char) -> Result<CompaDecimal, Error> {
+    read_str(input)?.parse().map_err(Error::invalid_input)
+}
+
+unsafe fn write_out<T>(out: *mut T, value: T) -> Result<(), Error> {
+    if out.is_null() {
+        return Err(Error::null_pointer());
+    }
+    out.write(value);
+    Ok(())
+}
+
+// `written` receives the length without the NUL terminator even when the buffer is
+// too small, so callers can retry with a buffer of `*written + 1` bytes.
+unsafe fn write_str(
+    value: &str,
+    out: *mut c_char,
+    out_len: usize,
+    written: *mut usize,
+) -> Result<(), Error> {
+    if !written.is_null() {
+        written.write(value.len());
+    }
+    if out.is_null() {
+        return Err(Error::null_pointer());
+    }
+    if out_len <= value.len() {
+        return Err(Error {
+            code: COMPA_ERR_BUFFER_TOO_SMALL,
+            message: format!(
+                "Output needs {} bytes including the terminator, got {}",
+                value.len() + 1,
+                out_len
+            ),
+        });
+    }
+    std::ptr::copy_nonoverlapping(value.as_ptr(), out.cast::<u8>(), value.len());
+    out.add(value.len()).write(0);
+    Ok(())
+}
+
+/// Encodes `value` into `out` as a NUL-terminated compa string.
+///
+/// # Safety
+/// `out` must point to `out_len` writable bytes. `written` may be null.
+#[no_mangle]
+pub unsafe extern "C" fn compa_encode_u64(
+    value: u64,
+    out: *mut c_char,
+    out_len: usize,
+    written: *mut usize,
+) -> i32 {
+    status(|| write_str(CompaDecimal::from(value).get_value(), out, out_len, written))
+}
+
+/// Decodes the NUL-terminated compa string `input` into `out`.
+///
+/// # Safety
+/// `input` must be a NUL-terminated string and `out` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn compa_decode_u64(input: *const c_char, out: *mut u64) -> i32 {
+    status(|| {
+        let num = read_compa(input)?
+            .to_decimal::<u64>()
+            .map_err(Error::overflow)?;
+        write_out(out, num)
+    })
+}
+
+/// Encodes `value` into `out` as a NUL-terminated compa string.
+///
+/// # Safety
+/// `out` must point to `out_len` writable bytes. `written` may be null.
+#[no_mangle]
+pub unsafe extern "C" fn compa_encode_u128(
+    value: u128,
+    out: *mut c_char,
+    out_len: usize,
+    written: *mut usize,
+) -> i32 {
+    status(|| write_str(CompaDecimal::from(value).get_value(), out, out_len, written))
+}
+
+/// Decodes the NUL-terminated compa string `input` into `out`.
+///
+/// # Safety
+/// `input` must be a NUL-terminated string and `out` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn compa_decode_u128(input: *const c_char, out: *mut u128) -> i32 {
+    status(|| {
+        let num = read_compa(input)?
+            .to_decimal::<u128>()
+            .map_err(Error::overflow)?;
+        write_out(out, num)
+    })
+}
+
+/// Encodes a decimal string of any length into `out` as a NUL-terminated compa string.
+///
+/// # Safety
+/// `decimal` must be a NUL-terminated string and `out` must point to `out_len`
+/// writable bytes. `written` may be null.
+#[no_mangle]
+pub unsafe extern "C" fn compa_encode_decimal(
+    decimal: *const c_char,
+    out: *mut c_char,
+    out_len: usize,
+    written: *mut usize,
+) -> i32 {
+    status(|| {
+        let compa =
+            CompaDecimal::from_decimal_str(read_str(decimal)?).map_err(Error::invalid_input)?;
+        write_str(compa.get_value(), out, out_len, written)
+    })
+}
+
+/// Adds two compa strings and writes the NUL-terminated sum into `out`.
+///
+/// # Safety
+/// `a` and `b` must be NUL-terminated strings and `out` must point to `out_len`
+/// writable bytes. `written` may be null.
+#[no_mangle]
+pub unsafe extern "C" fn compa_add(
+    a: *const c_char,
+    b: *const c_char,
+    out: *mut c_char,
+    out_len: usize,
+    written: *mut usize,
+) -> i32 {
+    status(|| {
+        let a = read_compa(a)?;
+        let b = read_compa(b)?;
+        write_str((&a + &b).get_value(), out, out_len, written)
+    })
+}
+
+/// Compares two compa strings by value and writes -1, 0 or 1 into `result`.
+///
+/// # Safety
+/// `a` and `b` must be NUL-terminated strings and `result` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn compa_cmp(a: *const c_char, b: *const c_char, result: *mut i32) -> i32 {
+    status(|| {
+        let a = read_compa(a)?;
+        let b = read_compa(b)?;
+        let ordering = a.cmp_str(b.get_value()).map_err(Error::invalid_input)?;
+        write_out(
+            result,
+            match ordering {
+                Ordering::Less => -1,
+                Ordering::Equal => 0,
+                Ordering::Greater => 1,
+            },
+        )
+    })
+}
+
+/// Copies the message of the last failure on this thread into `out`.
+///
+/// # Safety
+/// `out` must point to `out_len` writable bytes. `written` may be null.
+#[no_mangle]
+pub unsafe extern "C" fn compa_last_error(
+    out: *mut c_char,
+    out_len: usize,
+    written: *mut usize,
+) -> i32 {
+    // Failures here are not recorded, so the message survives a retry with a bigger
+    // buffer.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let message = LAST_ERROR.with(|last| last.borrow().clone());
+        write_str(&message, out, out_len, written)
+    }));
+    match result {
+        Ok(Ok(())) => COMPA_OK,
+        Ok(Err(error)) => error.code,
+        Err(_) => COMPA_ERR_PANIC,
+    }
+}
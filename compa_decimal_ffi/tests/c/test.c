@@ -0,0 +1,102 @@
+#include <stdio.h>
+#include <string.h>
+
+#include "compa_decimal.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                      \
+    do {                                                                 \
+        if (!(cond)) {                                                   \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,       \
+                    __LINE__, #cond);                                    \
+            failures++;                                                  \
+        }                                                                \
+    } while (0)
+
+static void test_u64(void) {
+    char buf[32];
+    size_t written = 0;
+    uint64_t value = 0;
+
+    CHECK(compa_encode_u64(123456789, buf, sizeof buf, &written) == COMPA_OK);
+    CHECK(strcmp(buf, "1T~PC") == 0);
+    CHECK(written == 5);
+
+    CHECK(compa_decode_u64("1T~PC", &value) == COMPA_OK);
+    CHECK(value == 123456789);
+
+    CHECK(compa_encode_u64(UINT64_MAX, buf, sizeof buf, NULL) == COMPA_OK);
+    CHECK(compa_decode_u64(buf, &value) == COMPA_OK);
+    CHECK(value == UINT64_MAX);
+}
+
+static void test_u128(void) {
+    char buf[32];
+    unsigned __int128 max = ~(unsigned __int128)0;
+    unsigned __int128 value = 0;
+
+    CHECK(compa_encode_u128(max, buf, sizeof buf, NULL) == COMPA_OK);
+    CHECK(compa_decode_u128(buf, &value) == COMPA_OK);
+    CHECK(value == max);
+
+    /* One more than u64::MAX no longer fits the narrower type. */
+    uint64_t narrow = 0;
+    CHECK(compa_encode_u128((unsigned __int128)UINT64_MAX + 1, buf, sizeof buf, NULL) == COMPA_OK);
+    CHECK(compa_decode_u64(buf, &narrow) == COMPA_ERR_OVERFLOW);
+}
+
+static void test_decimal_add_cmp(void) {
+    char buf[64];
+    int32_t order = 0;
+
+    CHECK(compa_encode_decimal("255", buf, sizeof buf, NULL) == COMPA_OK);
+    CHECK(strcmp(buf, "2$") == 0);
+
+    CHECK(compa_encode_decimal("12a", buf, sizeof buf, NULL) == COMPA_ERR_INVALID_INPUT);
+
+    CHECK(compa_add("1T~PC", "2$", buf, sizeof buf, NULL) == COMPA_OK);
+    uint64_t sum = 0;
+    CHECK(compa_decode_u64(buf, &sum) == COMPA_OK);
+    CHECK(sum == 123456789 + 255);
+
+    CHECK(compa_cmp("2$", "1T~PC", &order) == COMPA_OK);
+    CHECK(order == -1);
+    CHECK(compa_cmp("002$", "2$", &order) == COMPA_OK);
+    CHECK(order == 0);
+    CHECK(compa_cmp("1T~PC", "2$", &order) == COMPA_OK);
+    CHECK(order == 1);
+}
+
+static void test_errors(void) {
+    char small[3];
+    char message[128];
+    size_t written = 0;
+    uint64_t value = 0;
+
+    /* The required length is reported so the caller can retry. */
+    CHECK(compa_encode_u64(123456789, small, sizeof small, &written) == COMPA_ERR_BUFFER_TOO_SMALL);
+    CHECK(written == 5);
+
+    CHECK(compa_decode_u64("\xe2\x82\xac", &value) == COMPA_ERR_INVALID_INPUT);
+    CHECK(compa_decode_u64(NULL, &value) == COMPA_ERR_NULL_POINTER);
+    CHECK(compa_decode_u64("1", NULL) == COMPA_ERR_NULL_POINTER);
+    CHECK(compa_decode_u64("", &value) == COMPA_ERR_INVALID_INPUT);
+
+    CHECK(compa_last_error(message, sizeof message, &written) == COMPA_OK);
+    CHECK(written > 0);
+    CHECK(strlen(message) == written);
+}
+
+int main(void) {
+    test_u64();
+    test_u128();
+    test_decimal_add_cmp();
+    test_errors();
+    if (failures != 0) {
+        fprintf(stderr, "%d check(s) failed\n", failures);
+        return 1;
+    }
+    printf("all checks passed\n");
+    return 0;
+}
@@ -0,0 +1,57 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+// `cargo test` builds the rlib, but nothing guarantees a fresh static library next to
+// it, so the test builds its own into the test scratch directory. A separate target
+// directory keeps this nested cargo from waiting on the lock of the outer one.
+fn build_static_library(crate_dir: &Path, target_dir: &Path) -> PathBuf {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(cargo)
+        .args([
+            "rustc",
+            "--lib",
+            "--crate-type",
+            "staticlib",
+            "--manifest-path",
+        ])
+        .arg(crate_dir.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(target_dir)
+        .status()
+        .expect("cargo is needed to build the static library");
+    assert!(status.success(), "building the static library failed");
+    target_dir.join("debug").join("libcompa_decimal_ffi.a")
+}
+
+#[test]
+fn c_program_test() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let scratch = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    let library = build_static_library(crate_dir, &scratch.join("ffi_staticlib"));
+
+    let program = scratch.join("compa_c_test");
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(compiler)
+        .arg("-std=c11")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(crate_dir.join("include"))
+        .arg(crate_dir.join("tests/c/test.c"))
+        .arg(&library)
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&program)
+        .status()
+        .expect("a C compiler is needed to run this test");
+    assert!(status.success(), "compiling tests/c/test.c failed");
+
+    let output = Command::new(&program).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
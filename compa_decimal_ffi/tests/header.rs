@@ -0,0 +1,21 @@
+use std::{env, fs, path::Path};
+
+#[test]
+fn header_is_up_to_date() {
+    let crate_dir = env!("CARGO_MANIFEST_DIR");
+    let mut generated = Vec::new();
+    cbindgen::generate(crate_dir)
+        .expect("cbindgen could not read the crate")
+        .write(&mut generated);
+    let generated = String::from_utf8(generated).unwrap();
+
+    let path = Path::new(crate_dir).join("include/compa_decimal.h");
+    if env::var_os("UPDATE_HEADER").is_some() {
+        fs::write(&path, &generated).unwrap();
+    }
+    assert_eq!(
+        fs::read_to_string(&path).unwrap_or_default(),
+        generated,
+        "include/compa_decimal.h is stale, rerun with UPDATE_HEADER=1"
+    );
+}
@@ -0,0 +1,195 @@
+use std::{cmp::Ordering, num::IntErrorKind};
+
+use compa_decimal::CompaDecimal;
+use num_bigint::BigUint;
+
+pub const EXIT_INVALID_INPUT: u8 = 3;
+pub const EXIT_OVERFLOW: u8 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Target {
+    U64,
+    U128,
+    Big,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    InvalidInput(String),
+    Overflow(String),
+}
+
+impl Failure {
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Failure::InvalidInput(_) => EXIT_INVALID_INPUT,
+            Failure::Overflow(_) => EXIT_OVERFLOW,
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Failure::InvalidInput(_) => "invalid_input",
+            Failure::Overflow(_) => "overflow",
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            Failure::InvalidInput(message) | Failure::Overflow(message) => message,
+        }
+    }
+}
+
+pub fn parse_compa(input: &str) -> Result<CompaDecimal, Failure> {
+    if input.is_empty() {
+        return Err(Failure::InvalidInput("Empty compa value".to_string()));
+    }
+    input
+        .parse()
+        .map_err(|error: compa_decimal::CompaDecimalError| {
+            Failure::InvalidInput(error.error_message)
+        })
+}
+
+pub fn parse_decimal(input: &str, target: Target) -> Result<CompaDecimal, Failure> {
+    let input = input.trim();
+    let int_failure = |error: std::num::ParseIntError| match error.kind() {
+        IntErrorKind::PosOverflow => Failure::Overflow(format!(
+            "{} does not fit in a {}",
+            input,
+            target_name(target)
+        )),
+        _ => Failure::InvalidInput(format!("Invalid decimal value: {}", input)),
+    };
+    match target {
+        Target::U64 => Ok(CompaDecimal::from(
+            input.parse::<u64>().map_err(int_failure)?,
+        )),
+        Target::U128 => Ok(CompaDecimal::from(
+            input.parse::<u128>().map_err(int_failure)?,
+        )),
+        Target::Big => CompaDecimal::from_decimal_str(input)
+            .map_err(|error| Failure::InvalidInput(error.error_message)),
+    }
+}
+
+fn target_name(target: Target) -> &'static str {
+    match target {
+        Target::U64 => "u64",
+        Target::U128 => "u128",
+        Target::Big => "BigUint",
+    }
+}
+
+pub fn encode(input: &str, target: Target) -> Result<String, Failure> {
+    Ok(parse_decimal(input, target)?.to_string())
+}
+
+pub fn decode(input: &str, target: Target) -> Result<String, Failure> {
+    let compa = parse_compa(input)?;
+    match target {
+        Target::U64 => compa
+            .to_decimal::<u64>()
+            .map(|num| num.to_string())
+            .map_err(|error| Failure::Overflow(error.error_message)),
+        Target::U128 => compa
+            .to_decimal::<u128>()
+            .map(|num| num.to_string())
+            .map_err(|error| Failure::Overflow(error.error_message)),
+        Target::Big => Ok(BigUint::from(&compa).to_string()),
+    }
+}
+
+pub fn add(a: &str, b: &str) -> Result<String, Failure> {
+    let a = parse_compa(a)?;
+    let b = parse_compa(b)?;
+    Ok((&a + &b).to_string())
+}
+
+pub fn sub(a: &str, b: &str) -> Result<String, Failure> {
+    let a = parse_compa(a)?;
+    let b = parse_compa(b)?;
+    a.subtract_compa(&b)
+        .map(|result| result.to_string())
+        .map_err(|error| Failure::Overflow(error.error_message))
+}
+
+pub fn cmp(a: &str, b: &str) -> Result<String, Failure> {
+    let a = parse_compa(a)?;
+    let b = parse_compa(b)?;
+    let ordering = match BigUint::from(&a).cmp(&BigUint::from(&b)) {
+        Ordering::Less => "less",
+        Ordering::Equal => "equal",
+        Ordering::Greater => "greater",
+    };
+    Ok(ordering.to_string())
+}
+
+pub fn inc(input: &str, by: &CompaDecimal) -> Result<String, Failure> {
+    let compa = parse_compa(input)?;
+    Ok((&compa + by).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_test() {
+        assert_eq!(encode("123456789", Target::Big).unwrap(), "1T~PC");
+        assert_eq!(encode(" 123456789 ", Target::U64).unwrap(), "1T~PC");
+        assert_eq!(
+            encode("18446744073709551616", Target::U64)
+                .unwrap_err()
+                .kind(),
+            "overflow"
+        );
+        assert_eq!(
+            encode("18446744073709551616", Target::U128).unwrap(),
+            CompaDecimal::from(u64::MAX as u128 + 1).to_string()
+        );
+        assert_eq!(
+            encode("12a", Target::Big).unwrap_err().kind(),
+            "invalid_input"
+        );
+        assert_eq!(
+            encode("-1", Target::U64).unwrap_err().kind(),
+            "invalid_input"
+        );
+    }
+
+    #[test]
+    fn decode_test() {
+        assert_eq!(decode("1T~PC", Target::U64).unwrap(), "123456789");
+        assert_eq!(
+            decode("91\"<n.hl48T!YkTkA?1Z", Target::U64)
+                .unwrap_err()
+                .exit_code(),
+            EXIT_OVERFLOW
+        );
+        assert_eq!(
+            decode("91\"<n.hl48T!YkTkA?1Z", Target::Big).unwrap(),
+            u128::MAX.to_string()
+        );
+        assert_eq!(
+            decode("", Target::Big).unwrap_err().exit_code(),
+            EXIT_INVALID_INPUT
+        );
+        assert_eq!(
+            decode("1£", Target::Big).unwrap_err().exit_code(),
+            EXIT_INVALID_INPUT
+        );
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        assert_eq!(add("aAswf", "AsdgrW11").unwrap(), "AsdMX6XG");
+        assert_eq!(sub("AsdMX6XG", "AsdgrW11").unwrap(), "aAswf");
+        assert_eq!(sub("1", "2").unwrap_err().kind(), "overflow");
+        assert_eq!(add("1", "£").unwrap_err().kind(), "invalid_input");
+        assert_eq!(cmp("1~", "20").unwrap(), "less");
+        assert_eq!(cmp("01", "1").unwrap(), "equal");
+        assert_eq!(inc("1~", &CompaDecimal::from(1u8)).unwrap(), "20");
+    }
+}
@@ -1,43 +1,354 @@
-use core::num;
+mod commands;
+mod repl;
+mod transcode;
 
-use compa_decimal::compadecimal::*;
-use num_bigint::BigUint;
+use std::{
+    fs::File,
+    io::{self, Read, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
 
-fn main() {
-    // let i = 867550179;
-    // let b: u128 = 681028071162207;
-    // let result = i + b;
+use clap::{Parser, Subcommand};
+use commands::{Failure, Target};
+use serde_json::json;
+use transcode::{CsvOptions, Direction};
 
-    // let compa_decimal1 = CompaDecimal::from("AsdgrW11").unwrap();
-    // let result = compa_decimal1.to_decimal::<u128>().unwrap();
+#[derive(Parser)]
+#[command(
+    name = "compa_decimal_helper",
+    about = "Encode, decode and do arithmetic on compa values",
+    after_help = "Without VALUES, input is read from stdin, one value per line. Binary \
+                  commands read two values per line separated by a tab, because a space \
+                  is a compa digit.\n\nExit codes: 0 success, 2 usage error, 3 invalid \
+                  input, 4 overflow or negative result."
+)]
+struct Cli {
+    #[arg(long, global = true, help = "Print one JSON object per result")]
+    json: bool,
 
-    //let compa_decimal1 = CompaDecimal::decimal_to_compa::<u128>(681028938712386).unwrap();
-    // let compa = CompaDecimal::from("1LY7VK").unwrap();
-    // let increased = compa.increase_by::<u32>(1234).unwrap();
-    let compa_decimal1: CompaDecimal = "abc".parse().unwrap();
-    let number = compa_decimal1.to_biguint().unwrap();
-    //let number = compa_decimal1.to_decimal::<u128>().unwrap();
-    // compa_decimal1 = compa_decimal1.decrease_by::<u128>(234897382497).unwrap();
-    println!("{}", number);
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    #[command(about = "Convert decimal values to compa")]
+    Encode {
+        #[arg(
+            long,
+            value_enum,
+            default_value = "big",
+            help = "Integer type the input has to fit"
+        )]
+        to: Target,
+        values: Vec<String>,
+    },
+    #[command(about = "Convert compa values to decimal")]
+    Decode {
+        #[arg(
+            long,
+            value_enum,
+            default_value = "big",
+            help = "Integer type the output has to fit"
+        )]
+        to: Target,
+        values: Vec<String>,
+    },
+    #[command(about = "Add two compa values")]
+    Add {
+        #[arg(num_args = 0..=2)]
+        values: Vec<String>,
+    },
+    #[command(about = "Subtract the second compa value from the first")]
+    Sub {
+        #[arg(num_args = 0..=2)]
+        values: Vec<String>,
+    },
+    #[command(about = "Compare two compa values")]
+    Cmp {
+        #[arg(num_args = 0..=2)]
+        values: Vec<String>,
+    },
+    #[command(about = "Increment compa values")]
+    Inc {
+        #[arg(long, default_value = "1", help = "Decimal amount to add")]
+        by: String,
+        values: Vec<String>,
+    },
+    #[command(about = "Evaluate expressions interactively, one per line")]
+    Repl,
+    #[command(
+        about = "Convert selected CSV columns between decimal and compa",
+        after_help = "Rows with a value that cannot be converted are reported on stderr with \
+                      their line number and written out unchanged, or left out with \
+                      --skip-invalid. Empty cells are kept. Fields are quoted only where \
+                      needed, so quoting can differ from the input."
+    )]
+    Csv {
+        #[arg(value_enum)]
+        direction: Direction,
+        #[arg(
+            long,
+            required = true,
+            value_delimiter = ',',
+            help = "Header names or 1-based indices of the columns to convert"
+        )]
+        columns: Vec<String>,
+        #[arg(
+            long,
+            value_enum,
+            default_value = "big",
+            help = "Integer type the values have to fit"
+        )]
+        to: Target,
+        #[arg(long, help = "Treat the first row as data instead of headers")]
+        no_headers: bool,
+        #[arg(long, default_value_t = ',', help = "Field delimiter")]
+        delimiter: char,
+        #[arg(long, help = "Leave rows that fail to convert out of the output")]
+        skip_invalid: bool,
+        #[arg(help = "CSV file to read instead of stdin")]
+        file: Option<PathBuf>,
+    },
+}
+
+// What one line of input holds for a command, so `run` can report it without knowing
+// which command it is running.
+trait Operands {
+    fn to_json(&self) -> serde_json::Value;
+    fn describe(&self) -> String;
+}
+
+impl Operands for String {
+    fn to_json(&self) -> serde_json::Value {
+        json!(self)
+    }
+
+    fn describe(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl Operands for (String, String) {
+    fn to_json(&self) -> serde_json::Value {
+        json!([self.0, self.1])
+    }
+
+    fn describe(&self) -> String {
+        format!("{:?} {:?}", self.0, self.1)
+    }
+}
+
+type Inputs<T> = Box<dyn Iterator<Item = io::Result<Result<T, Failure>>>>;
 
-    let back_to_compa = CompaDecimal::biguint_to_compa(&BigUint::from(123456789_u128)).unwrap();
-    println!("{}", back_to_compa);
-    // let size = 100;
-    // let n = generated_random_biguint(size);
-    // let m = biguint_to_compa(n);
-    // let m_size = m.len();
-    // println!("a number with a {} digit goes down to {}", size, m_size);
+// Lines are read as they arrive, so each result is written before the next line is
+// read and the helper works at the end of a pipe that stays open.
+fn stdin_lines() -> impl Iterator<Item = io::Result<String>> {
+    io::stdin()
+        .lines()
+        .map(|line| line.map(|line| line.trim_end_matches('\r').to_string()))
 }
 
-// fn generated_random_biguint(digits: usize) -> BigUint {
-//     let mut rng = rand::rng();
-//     let mut number = String::new();
+fn single_inputs(values: Vec<String>) -> Inputs<String> {
+    if values.is_empty() {
+        Box::new(stdin_lines().map(|line| line.map(Ok)))
+    } else {
+        Box::new(values.into_iter().map(|value| Ok(Ok(value))))
+    }
+}
+
+fn pair_inputs(values: Vec<String>) -> Inputs<(String, String)> {
+    if let [a, b] = values.as_slice() {
+        return Box::new(std::iter::once(Ok(Ok((a.clone(), b.clone())))));
+    }
+    Box::new(stdin_lines().map(|line| {
+        line.map(|line| match line.split_once('\t') {
+            Some((a, b)) => Ok((a.to_string(), b.to_string())),
+            None => Err(Failure::InvalidInput(format!(
+                "Expected two tab-separated values, got {:?}",
+                line
+            ))),
+        })
+    }))
+}
+
+fn run<T, F>(inputs: Inputs<T>, json: bool, operation: F) -> ExitCode
+where
+    T: Operands,
+    F: Fn(&T) -> Result<String, Failure>,
+{
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    let mut exit_code = 0;
+
+    for (index, input) in inputs.enumerate() {
+        let input = match input {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("error: failed to read stdin: {}", error);
+                return ExitCode::FAILURE;
+            }
+        };
+        let result = match &input {
+            Ok(input) => operation(input),
+            Err(failure) => Err(failure.clone()),
+        };
+        let written = match (&result, json) {
+            (Ok(output), true) => writeln!(
+                stdout,
+                "{}",
+                json!({ "input": input.as_ref().ok().map(T::to_json), "output": output })
+            ),
+            (Ok(output), false) => writeln!(stdout, "{}", output),
+            (Err(failure), true) => writeln!(
+                stdout,
+                "{}",
+                json!({
+                    "input": input.as_ref().ok().map(T::to_json),
+                    "line": index + 1,
+                    "error": failure.kind(),
+                    "message": failure.message(),
+                })
+            ),
+            (Err(failure), false) => {
+                let described = input
+                    .as_ref()
+                    .map(T::describe)
+                    .unwrap_or_else(|_| "input".to_string());
+                eprintln!(
+                    "error: line {}: {}: {}",
+                    index + 1,
+                    described,
+                    failure.message()
+                );
+                Ok(())
+            }
+        };
+        if written.is_err() {
+            return ExitCode::FAILURE;
+        }
+        if let Err(failure) = result {
+            if exit_code == 0 {
+                exit_code = failure.exit_code();
+            }
+        }
+    }
+
+    ExitCode::from(exit_code)
+}
+
+fn run_pairs(
+    values: Vec<String>,
+    json: bool,
+    operation: fn(&str, &str) -> Result<String, Failure>,
+) -> ExitCode {
+    if values.len() == 1 {
+        eprintln!("error: expected two values or none to read pairs from stdin");
+        return ExitCode::from(2);
+    }
+    run(pair_inputs(values), json, |(a, b)| operation(a, b))
+}
 
-//     number.push(rng.random_range(1..10).to_string().chars().next().unwrap());
+fn run_csv(options: CsvOptions, file: Option<PathBuf>, json: bool) -> ExitCode {
+    let input: Box<dyn Read> = match file {
+        Some(path) => match File::open(&path) {
+            Ok(file) => Box::new(file),
+            Err(error) => {
+                eprintln!("error: failed to open {}: {}", path.display(), error);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Box::new(io::stdin().lock()),
+    };
+    let failures = match transcode::transcode(input, io::stdout().lock(), &options) {
+        Ok(failures) => failures,
+        Err(error) => {
+            eprintln!("error: {}", error.message());
+            return ExitCode::from(error.exit_code());
+        }
+    };
 
-//     for _ in 1..digits {
-//         number.push(rng.random_range(1..10).to_string().chars().next().unwrap());
-//     }
+    for row in &failures {
+        if json {
+            eprintln!(
+                "{}",
+                json!({
+                    "line": row.line,
+                    "column": row.column,
+                    "error": row.failure.kind(),
+                    "message": row.failure.message(),
+                })
+            );
+        } else {
+            eprintln!(
+                "error: line {}: column {:?}: {}",
+                row.line,
+                row.column,
+                row.failure.message()
+            );
+        }
+    }
+    match failures.first() {
+        Some(row) => ExitCode::from(row.failure.exit_code()),
+        None => ExitCode::SUCCESS,
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
 
-//     BigUint::parse_bytes(number.as_bytes(), 10).unwrap() 867550179 + 681028071162207
-// }
+    match cli.command {
+        Command::Repl => {
+            let stdout = io::stdout();
+            match repl::run(io::stdin().lock(), stdout.lock()) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(_) => ExitCode::FAILURE,
+            }
+        }
+        Command::Csv {
+            direction,
+            columns,
+            to,
+            no_headers,
+            delimiter,
+            skip_invalid,
+            file,
+        } => {
+            if !delimiter.is_ascii() {
+                eprintln!("error: the delimiter has to be a single ASCII character");
+                return ExitCode::from(2);
+            }
+            let options = CsvOptions {
+                direction,
+                columns,
+                target: to,
+                headers: !no_headers,
+                delimiter: delimiter as u8,
+                skip_invalid,
+            };
+            run_csv(options, file, cli.json)
+        }
+        Command::Encode { to, values } => run(single_inputs(values), cli.json, |value| {
+            commands::encode(value, to)
+        }),
+        Command::Decode { to, values } => run(single_inputs(values), cli.json, |value| {
+            commands::decode(value, to)
+        }),
+        Command::Inc { by, values } => {
+            let by = match commands::parse_decimal(&by, Target::Big) {
+                Ok(by) => by,
+                Err(failure) => {
+                    eprintln!("error: --by: {}", failure.message());
+                    return ExitCode::from(failure.exit_code());
+                }
+            };
+            run(single_inputs(values), cli.json, |value| {
+                commands::inc(value, &by)
+            })
+        }
+        Command::Add { values } => run_pairs(values, cli.json, commands::add),
+        Command::Sub { values } => run_pairs(values, cli.json, commands::sub),
+        Command::Cmp { values } => run_pairs(values, cli.json, commands::cmp),
+    }
+}
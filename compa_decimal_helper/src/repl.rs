@@ -0,0 +1,380 @@
+use std::{
+    collections::BTreeMap,
+    io::{self, BufRead, Write},
+};
+
+use compa_decimal::CompaDecimal;
+use num::Zero;
+
+const HELP: &str = "\
+Expressions combine values with + - * / % and parentheses.
+  1T~PC        compa literal made of 0-9, A-Z and a-z
+  '1 ~'        quoted compa literal for any compa digit, escape ' and \\ with \\
+  #123456789   decimal literal
+  $name        variable, assign with $name = <expression>
+  $_ / $3      last result / result number 3
+Commands: :help  :vars  :history  :quit";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Compa(String),
+    Decimal(String),
+    Variable(String),
+    History(usize),
+    Operator(char),
+}
+
+fn tokenize(line: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+
+    while let Some(&ch) = chars.peek() {
+        match ch {
+            ' ' | '\t' => {
+                chars.next();
+            }
+            '+' | '-' | '*' | '/' | '%' | '(' | ')' | '=' => {
+                chars.next();
+                tokens.push(Token::Operator(ch));
+            }
+            '\'' => {
+                chars.next();
+                let mut literal = String::new();
+                loop {
+                    match chars.next() {
+                        Some('\\') => match chars.next() {
+                            Some(escaped @ ('\'' | '\\')) => literal.push(escaped),
+                            _ => return Err("Only \\' and \\\\ can be escaped".to_string()),
+                        },
+                        Some('\'') => break,
+                        Some(ch) => literal.push(ch),
+                        None => return Err("Unterminated quoted literal".to_string()),
+                    }
+                }
+                tokens.push(Token::Compa(literal));
+            }
+            '#' => {
+                chars.next();
+                let mut literal = String::new();
+                while let Some(&ch) = chars.peek().filter(|ch| ch.is_ascii_digit()) {
+                    literal.push(ch);
+                    chars.next();
+                }
+                if literal.is_empty() {
+                    return Err("Expected decimal digits after #".to_string());
+                }
+                tokens.push(Token::Decimal(literal));
+            }
+            '$' => {
+                chars.next();
+                let mut name = String::new();
+                while let Some(&ch) = chars
+                    .peek()
+                    .filter(|ch| ch.is_ascii_alphanumeric() || **ch == '_')
+                {
+                    name.push(ch);
+                    chars.next();
+                }
+                if name.is_empty() {
+                    return Err("Expected a variable name after $".to_string());
+                }
+                if name.chars().all(|ch| ch.is_ascii_digit()) {
+                    let index = name
+                        .parse()
+                        .map_err(|_| format!("Invalid history reference ${}", name))?;
+                    tokens.push(Token::History(index));
+                } else {
+                    tokens.push(Token::Variable(name));
+                }
+            }
+            ch if ch.is_ascii_alphanumeric() => {
+                let mut literal = String::new();
+                while let Some(&ch) = chars.peek().filter(|ch| ch.is_ascii_alphanumeric()) {
+                    literal.push(ch);
+                    chars.next();
+                }
+                tokens.push(Token::Compa(literal));
+            }
+            other => {
+                return Err(format!(
+                    "Unexpected character '{}', quote compa literals that contain symbols",
+                    other
+                ))
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[derive(Debug, Default)]
+pub struct Session {
+    variables: BTreeMap<String, CompaDecimal>,
+    history: Vec<(String, CompaDecimal)>,
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+    session: &'a Session,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.position);
+        self.position += 1;
+        token
+    }
+
+    fn expression(&mut self) -> Result<CompaDecimal, String> {
+        let mut value = self.term()?;
+        while let Some(Token::Operator(op @ ('+' | '-'))) = self.peek() {
+            let op = *op;
+            self.next();
+            let rhs = self.term()?;
+            value = match op {
+                '+' => value.add(rhs.get_value()),
+                _ => value.subtract(rhs.get_value()),
+            }
+            .map_err(|error| error.error_message)?;
+        }
+        Ok(value)
+    }
+
+    fn term(&mut self) -> Result<CompaDecimal, String> {
+        let mut value = self.factor()?;
+        while let Some(Token::Operator(op @ ('*' | '/' | '%'))) = self.peek() {
+            let op = *op;
+            self.next();
+            let rhs = self.factor()?;
+            if op != '*' && rhs.is_zero() {
+                return Err("Division by zero".to_string());
+            }
+            value = match op {
+                '*' => &value * &rhs,
+                '/' => &value / &rhs,
+                _ => &value % &rhs,
+            };
+        }
+        Ok(value)
+    }
+
+    fn factor(&mut self) -> Result<CompaDecimal, String> {
+        match self.next().cloned() {
+            Some(Token::Compa(literal)) => literal
+                .parse()
+                .map_err(|error: compa_decimal::CompaDecimalError| error.error_message),
+            Some(Token::Decimal(literal)) => {
+                CompaDecimal::from_decimal_str(&literal).map_err(|error| error.error_message)
+            }
+            Some(Token::Variable(name)) => self.session.variable(&name),
+            Some(Token::History(index)) => self.session.history_entry(index),
+            Some(Token::Operator('(')) => {
+                let value = self.expression()?;
+                match self.next() {
+                    Some(Token::Operator(')')) => Ok(value),
+                    _ => Err("Expected ')'".to_string()),
+                }
+            }
+            Some(token) => Err(format!("Unexpected {:?}", token)),
+            None => Err("Unexpected end of expression".to_string()),
+        }
+    }
+}
+
+impl Session {
+    pub fn new() -> Session {
+        Session::default()
+    }
+
+    fn variable(&self, name: &str) -> Result<CompaDecimal, String> {
+        if name == "_" {
+            return self
+                .history
+                .last()
+                .map(|(_, value)| value.clone())
+                .ok_or_else(|| "No previous result".to_string());
+        }
+        self.variables
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("Unknown variable ${}", name))
+    }
+
+    fn history_entry(&self, index: usize) -> Result<CompaDecimal, String> {
+        index
+            .checked_sub(1)
+            .and_then(|index| self.history.get(index))
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| format!("No result ${}", index))
+    }
+
+    pub fn evaluate(&mut self, line: &str) -> Result<CompaDecimal, String> {
+        let tokens = tokenize(line)?;
+        let (target, expression) = match tokens.as_slice() {
+            [Token::Variable(name), Token::Operator('='), rest @ ..] => {
+                if name == "_" {
+                    return Err("$_ is read-only".to_string());
+                }
+                (Some(name.clone()), rest)
+            }
+            _ => (None, tokens.as_slice()),
+        };
+
+        let mut parser = Parser {
+            tokens: expression,
+            position: 0,
+            session: self,
+        };
+        let value = parser.expression()?;
+        if let Some(token) = parser.peek() {
+            return Err(format!("Unexpected {:?}", token));
+        }
+
+        if let Some(name) = target {
+            self.variables.insert(name, value.clone());
+        }
+        self.history.push((line.to_string(), value.clone()));
+        Ok(value)
+    }
+
+    pub fn describe(&self, value: &CompaDecimal) -> String {
+        format!(
+            "[{}] {}  = {}  = 0x{}",
+            self.history.len(),
+            value,
+            value.to_decimal_string(),
+            value
+                .to_string_radix_std(16)
+                .expect("16 is a supported radix")
+        )
+    }
+
+    fn command(&self, command: &str) -> Option<String> {
+        match command {
+            ":help" => Some(HELP.to_string()),
+            ":vars" => Some(
+                self.variables
+                    .iter()
+                    .map(|(name, value)| format!("${} = {}", name, value))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            ),
+            ":history" => Some(
+                self.history
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (line, value))| format!("[{}] {}  => {}", i + 1, line, value))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            ),
+            _ => None,
+        }
+    }
+}
+
+pub fn run(input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    let mut session = Session::new();
+    write!(output, "compa> ")?;
+    output.flush()?;
+
+    for line in input.lines() {
+        let line = line?;
+        let line = line.trim_end_matches('\r');
+        let command = line.trim();
+        if command == ":quit" || command == ":q" {
+            break;
+        }
+        if command.starts_with(':') {
+            match session.command(command) {
+                Some(text) if !text.is_empty() => writeln!(output, "{}", text)?,
+                Some(_) => {}
+                None => writeln!(output, "error: unknown command {}", command)?,
+            }
+        } else if !command.is_empty() {
+            match session.evaluate(line) {
+                Ok(value) => writeln!(output, "{}", session.describe(&value))?,
+                Err(message) => writeln!(output, "error: {}", message)?,
+            }
+        }
+        write!(output, "compa> ")?;
+        output.flush()?;
+    }
+
+    writeln!(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_test() {
+        assert_eq!(
+            tokenize("1T + '~ \\'' * #16").unwrap(),
+            vec![
+                Token::Compa("1T".to_string()),
+                Token::Operator('+'),
+                Token::Compa("~ '".to_string()),
+                Token::Operator('*'),
+                Token::Decimal("16".to_string()),
+            ]
+        );
+        assert_eq!(
+            tokenize("$x = $_ - $2").unwrap(),
+            vec![
+                Token::Variable("x".to_string()),
+                Token::Operator('='),
+                Token::Variable("_".to_string()),
+                Token::Operator('-'),
+                Token::History(2),
+            ]
+        );
+        assert!(tokenize("1 ~").is_err());
+        assert!(tokenize("'abc").is_err());
+        assert!(tokenize("#").is_err());
+    }
+
+    #[test]
+    fn evaluate_test() {
+        let mut session = Session::new();
+        assert_eq!(session.evaluate("aAswf + AsdgrW11").unwrap(), "AsdMX6XG");
+        assert_eq!(session.evaluate("#123456789").unwrap(), "1T~PC");
+        assert_eq!(session.evaluate("(#2 + #3) * #4").unwrap(), "F");
+        assert_eq!(session.evaluate("#2 + #3 * #4").unwrap(), "C");
+        assert_eq!(session.evaluate("'1~' + 1").unwrap(), "20");
+        assert_eq!(session.evaluate("#100 / #7 % #5").unwrap(), "4");
+        assert!(session.evaluate("1 - 2").is_err());
+        assert!(session.evaluate("1 / 0").is_err());
+        assert!(session.evaluate("(1 + 2").is_err());
+        assert!(session.evaluate("1 2").is_err());
+    }
+
+    #[test]
+    fn variables_history_test() {
+        let mut session = Session::new();
+        assert_eq!(session.evaluate("$id = #123456789").unwrap(), "1T~PC");
+        assert_eq!(session.evaluate("$id + 1").unwrap(), "1T~Pc");
+        assert_eq!(session.evaluate("$_ - $1").unwrap(), "1");
+        assert_eq!(session.evaluate("$2").unwrap(), "1T~Pc");
+        assert!(session.evaluate("$missing").is_err());
+        assert!(session.evaluate("$9").is_err());
+        assert!(session.evaluate("$_ = 1").is_err());
+        assert_eq!(session.command(":vars").unwrap(), "$id = 1T~PC");
+        assert!(session
+            .command(":history")
+            .unwrap()
+            .starts_with("[1] $id = #123456789  => 1T~PC"));
+    }
+
+    #[test]
+    fn describe_test() {
+        let mut session = Session::new();
+        let value = session.evaluate("#255").unwrap();
+        assert_eq!(session.describe(&value), "[1] 2$  = 255  = 0xff");
+    }
+}
@@ -0,0 +1,256 @@
+use std::{
+    borrow::Cow,
+    io::{Read, Write},
+};
+
+use crate::commands::{self, Failure, Target};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Direction {
+    Encode,
+    Decode,
+}
+
+pub struct CsvOptions {
+    pub direction: Direction,
+    pub columns: Vec<String>,
+    pub target: Target,
+    pub headers: bool,
+    pub delimiter: u8,
+    pub skip_invalid: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowFailure {
+    pub line: u64,
+    pub column: String,
+    pub failure: Failure,
+}
+
+#[derive(Debug)]
+pub enum TranscodeError {
+    UnknownColumn(String),
+    Csv(csv::Error),
+}
+
+impl TranscodeError {
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            TranscodeError::UnknownColumn(_) => 2,
+            TranscodeError::Csv(_) => 1,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            TranscodeError::UnknownColumn(column) => format!("Unknown column {:?}", column),
+            TranscodeError::Csv(error) => error.to_string(),
+        }
+    }
+}
+
+impl From<csv::Error> for TranscodeError {
+    fn from(error: csv::Error) -> Self {
+        TranscodeError::Csv(error)
+    }
+}
+
+impl From<std::io::Error> for TranscodeError {
+    fn from(error: std::io::Error) -> Self {
+        TranscodeError::Csv(error.into())
+    }
+}
+
+// Columns are matched by header name first and fall back to 1-based indices, which
+// are the only option without a header row.
+fn resolve_columns(
+    columns: &[String],
+    headers: Option<&csv::ByteRecord>,
+) -> Result<Vec<(usize, String)>, TranscodeError> {
+    columns
+        .iter()
+        .map(|column| {
+            let by_name =
+                headers.and_then(|headers| headers.iter().position(|h| h == column.as_bytes()));
+            let by_index = column
+                .parse::<usize>()
+                .ok()
+                .filter(|&index| index > 0)
+                .map(|index| index - 1);
+            by_name
+                .or(by_index)
+                .map(|index| (index, column.clone()))
+                .ok_or_else(|| TranscodeError::UnknownColumn(column.clone()))
+        })
+        .collect()
+}
+
+fn convert(value: &str, options: &CsvOptions) -> Result<String, Failure> {
+    match options.direction {
+        Direction::Encode => commands::encode(value, options.target),
+        Direction::Decode => commands::decode(value, options.target),
+    }
+}
+
+// A row with a value that cannot be converted is returned as a failure and written out
+// unchanged, or left out with `skip_invalid`, so one bad row does not stop a long
+// migration. Cells are handled as bytes, so only the converted columns have to be
+// UTF-8. The writer quotes a field only when it has to, so quoting in the output can
+// differ from the input even where the values are the same.
+pub fn transcode(
+    input: impl Read,
+    output: impl Write,
+    options: &CsvOptions,
+) -> Result<Vec<RowFailure>, TranscodeError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(options.delimiter)
+        .from_reader(input);
+    let mut writer = csv::WriterBuilder::new()
+        .flexible(true)
+        .delimiter(options.delimiter)
+        .quote_style(csv::QuoteStyle::Necessary)
+        .from_writer(output);
+    let mut failures = Vec::new();
+    let mut columns = None;
+    let mut record = csv::ByteRecord::new();
+
+    loop {
+        let line = reader.position().line();
+        if !reader.read_byte_record(&mut record)? {
+            break;
+        }
+
+        let columns = match &columns {
+            Some(columns) => columns,
+            None if options.headers => {
+                columns = Some(resolve_columns(&options.columns, Some(&record))?);
+                writer.write_byte_record(&record)?;
+                continue;
+            }
+            None => columns.insert(resolve_columns(&options.columns, None)?),
+        };
+
+        let mut converted: Vec<Cow<[u8]>> = record.iter().map(Cow::Borrowed).collect();
+        let mut row_failed = false;
+        for (index, column) in columns {
+            // Empty and missing cells stay as they are, so nullable ID columns survive.
+            let Some(cell) = converted.get_mut(*index).filter(|cell| !cell.is_empty()) else {
+                continue;
+            };
+            let result = match std::str::from_utf8(cell) {
+                Ok(value) => convert(value, options),
+                Err(_) => Err(Failure::InvalidInput(
+                    "Value is not valid UTF-8".to_string(),
+                )),
+            };
+            match result {
+                Ok(result) => *cell = Cow::Owned(result.into_bytes()),
+                Err(failure) => {
+                    row_failed = true;
+                    failures.push(RowFailure {
+                        line,
+                        column: column.clone(),
+                        failure,
+                    });
+                }
+            }
+        }
+        if !row_failed {
+            writer.write_record(&converted)?;
+        } else if !options.skip_invalid {
+            writer.write_byte_record(&record)?;
+        }
+    }
+
+    writer.flush()?;
+    Ok(failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(direction: Direction, columns: &[&str], headers: bool) -> CsvOptions {
+        CsvOptions {
+            direction,
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+            target: Target::Big,
+            headers,
+            delimiter: b',',
+            skip_invalid: false,
+        }
+    }
+
+    fn run(input: &str, options: &CsvOptions) -> (String, Vec<RowFailure>) {
+        let mut output = Vec::new();
+        let failures = transcode(input.as_bytes(), &mut output, options).unwrap();
+        (String::from_utf8(output).unwrap(), failures)
+    }
+
+    #[test]
+    fn encode_columns_test() {
+        let (output, failures) = run(
+            "id,name,parent\n123456789,\"Smith, J\",\n11,x,12\n",
+            &options(Direction::Encode, &["id", "parent"], true),
+        );
+        assert!(failures.is_empty());
+        assert_eq!(output, "id,name,parent\n1T~PC,\"Smith, J\",\na,x,B\n");
+    }
+
+    #[test]
+    fn quoting_round_trip_test() {
+        // 263 encodes to `2,` and 15012 to `1"2`.
+        let input = "id\n263\n15012\n";
+        let (encoded, _) = run(input, &options(Direction::Encode, &["id"], true));
+        assert_eq!(encoded, "id\n\"2,\"\n\"1\"\"2\"\n");
+        let (decoded, failures) = run(&encoded, &options(Direction::Decode, &["id"], true));
+        assert!(failures.is_empty());
+        assert_eq!(decoded, input);
+    }
+
+    #[test]
+    fn bad_rows_test() {
+        let input = "95,a\nx,b\n96,c\n-3,d\n";
+        let mut options = options(Direction::Encode, &["1"], false);
+        let (output, failures) = run(input, &options);
+        assert_eq!(output, "10,a\nx,b\n11,c\n-3,d\n");
+        assert_eq!(
+            failures.iter().map(|f| f.line).collect::<Vec<u64>>(),
+            vec![2, 4]
+        );
+        assert_eq!(failures[0].failure.kind(), "invalid_input");
+
+        options.skip_invalid = true;
+        let (output, failures) = run(input, &options);
+        assert_eq!(output, "10,a\n11,c\n");
+        assert_eq!(failures.len(), 2);
+    }
+
+    #[test]
+    fn non_utf8_cells_test() {
+        let mut output = Vec::new();
+        let failures = transcode(
+            &b"95,\xFF\n\xFF9,a\n"[..],
+            &mut output,
+            &options(Direction::Encode, &["1"], false),
+        )
+        .unwrap();
+        assert_eq!(output, b"10,\xFF\n\xFF9,a\n");
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].line, 2);
+    }
+
+    #[test]
+    fn unknown_column_test() {
+        let mut output = Vec::new();
+        let error = transcode(
+            "id\n1\n".as_bytes(),
+            &mut output,
+            &options(Direction::Decode, &["missing"], true),
+        )
+        .unwrap_err();
+        assert_eq!(error.exit_code(), 2);
+    }
+}
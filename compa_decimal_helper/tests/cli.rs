@@ -0,0 +1,181 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    process::{Command, Output, Stdio},
+};
+
+fn helper(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_compa_decimal_helper"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn encode_decode_args_test() {
+    let output = helper(&["encode", "123456789", "16"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "1T~PC\nD\n");
+
+    let output = helper(&["decode", "--to", "u64", "1T~PC"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "123456789\n");
+}
+
+#[test]
+fn batch_stdin_json_test() {
+    let output = helper(&["decode", "--json"], "1T~PC\n1 ~\r\n");
+    assert_eq!(output.status.code(), Some(0));
+    let lines: Vec<serde_json::Value> = stdout(&output)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines[0]["input"], "1T~PC");
+    assert_eq!(lines[0]["output"], "123456789");
+    assert_eq!(lines[1]["input"], "1 ~");
+    assert_eq!(lines[1]["output"], "17954");
+}
+
+#[test]
+fn stdin_streaming_test() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_compa_decimal_helper"))
+        .arg("decode")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    let mut line = String::new();
+
+    // Each answer has to arrive while stdin is still open.
+    for (input, output) in [("1T~PC\n", "123456789\n"), ("2$\n", "255\n")] {
+        stdin.write_all(input.as_bytes()).unwrap();
+        stdin.flush().unwrap();
+        line.clear();
+        stdout.read_line(&mut line).unwrap();
+        assert_eq!(line, output);
+    }
+    drop(stdin);
+    assert!(child.wait().unwrap().success());
+}
+
+#[test]
+fn exit_codes_test() {
+    let output = helper(&["decode", "1T~PC£"], "");
+    assert_eq!(output.status.code(), Some(3));
+
+    let output = helper(&["decode", "--to", "u64", "91\"<n.hl48T!YkTkA?1Z"], "");
+    assert_eq!(output.status.code(), Some(4));
+
+    let output = helper(&["encode", "--to", "u64", "18446744073709551616"], "");
+    assert_eq!(output.status.code(), Some(4));
+
+    let output = helper(&["sub", "1", "2"], "");
+    assert_eq!(output.status.code(), Some(4));
+
+    let output = helper(&["add", "1"], "");
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = helper(&["encode", "--json"], "1\nabc\n2\n");
+    assert_eq!(output.status.code(), Some(3));
+    let lines: Vec<serde_json::Value> = stdout(&output)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[1]["error"], "invalid_input");
+    assert_eq!(lines[1]["line"], 2);
+    assert_eq!(lines[2]["output"], "2");
+}
+
+#[test]
+fn binary_commands_test() {
+    let output = helper(&["add", "aAswf", "AsdgrW11"], "");
+    assert_eq!(stdout(&output), "AsdMX6XG\n");
+
+    let output = helper(&["sub"], "AsdMX6XG\tAsdgrW11\n2\t1\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "aAswf\n1\n");
+
+    let output = helper(&["cmp", "--json"], "1~\t20\n1 2\n");
+    assert_eq!(output.status.code(), Some(3));
+    let lines: Vec<serde_json::Value> = stdout(&output)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines[0]["output"], "less");
+    assert_eq!(lines[1]["error"], "invalid_input");
+
+    // cmp and sub have to read "0c5" as the same value.
+    let output = helper(&["cmp", "0c5", "c5"], "");
+    assert_eq!(stdout(&output), "equal\n");
+    let output = helper(&["sub", "c5", "0c5"], "");
+    assert_eq!(stdout(&output), "0\n");
+    let output = helper(&["sub", "5", "0c5"], "");
+    assert_eq!(output.status.code(), Some(4));
+
+    let output = helper(&["inc", "--by", "2", "1~", "0"], "");
+    assert_eq!(stdout(&output), "21\n2\n");
+}
+
+#[test]
+fn repl_test() {
+    let output = helper(
+        &["repl"],
+        "$id = #123456789\n$id + '1 ~' * 2\n1 - 2\n:vars\n",
+    );
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        stdout(&output),
+        "compa> [1] 1T~PC  = 123456789  = 0x75bcd15\n\
+         compa> [2] 1t3OB  = 123492697  = 0x75c5959\n\
+         compa> error: Result would be negative\n\
+         compa> $id = 1T~PC\n\
+         compa> \n"
+    );
+}
+
+#[test]
+fn csv_test() {
+    let output = helper(
+        &["csv", "encode", "--columns", "id,parent"],
+        "id,name,parent\n123456789,\"Smith, J\",\n263,x,abc\n15012,y,1\n",
+    );
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(
+        stdout(&output),
+        "id,name,parent\n1T~PC,\"Smith, J\",\n263,x,abc\n\"1\"\"2\",y,1\n"
+    );
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "error: line 3: column \"parent\": Invalid compa digit 'a' at byte 0 for radix 10\n"
+    );
+
+    let output = helper(
+        &["csv", "encode", "--columns", "id", "--skip-invalid"],
+        "id,name\nabc,x\n95,y\n",
+    );
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(stdout(&output), "id,name\n10,y\n");
+
+    let output = helper(
+        &["csv", "decode", "--no-headers", "--columns", "1"],
+        "\"2,\",x\n",
+    );
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "263,x\n");
+}
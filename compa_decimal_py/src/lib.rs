@@ -0,0 +1,193 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use compa_decimal::{CompaDecimal, CompaDecimalError};
+use num_bigint::{BigInt, BigUint};
+use pyo3::{
+    basic::CompareOp,
+    exceptions::{PyValueError, PyZeroDivisionError},
+    prelude::*,
+};
+
+fn value_error(error: CompaDecimalError) -> PyErr {
+    PyValueError::new_err(error.error_message)
+}
+
+fn from_int(num: BigInt) -> PyResult<CompaDecimal> {
+    let num = num
+        .to_biguint()
+        .ok_or_else(|| PyValueError::new_err("CompaDecimal cannot hold a negative number"))?;
+    Ok(CompaDecimal::from(num))
+}
+
+fn nonzero(divisor: CompaDecimal) -> PyResult<CompaDecimal> {
+    if divisor.get_value().bytes().all(|digit| digit == b'0') {
+        return Err(PyZeroDivisionError::new_err("division by zero"));
+    }
+    Ok(divisor)
+}
+
+// Arithmetic takes another CompaDecimal or a Python int. Anything else fails to
+// extract, which makes pyo3 return NotImplemented so Python can try the other side.
+#[derive(FromPyObject)]
+enum Operand {
+    Compa(PyCompaDecimal),
+    Int(BigInt),
+}
+
+impl Operand {
+    fn into_compa(self) -> PyResult<CompaDecimal> {
+        match self {
+            Operand::Compa(compa) => Ok(compa.inner),
+            Operand::Int(num) => from_int(num),
+        }
+    }
+}
+
+#[derive(FromPyObject)]
+enum Value {
+    Compa(PyCompaDecimal),
+    Int(BigInt),
+    Str(String),
+}
+
+#[pyclass(
+    name = "CompaDecimal",
+    module = "compa_decimal",
+    frozen,
+    from_py_object
+)]
+#[derive(Clone)]
+struct PyCompaDecimal {
+    inner: CompaDecimal,
+}
+
+impl From<CompaDecimal> for PyCompaDecimal {
+    fn from(inner: CompaDecimal) -> PyCompaDecimal {
+        PyCompaDecimal { inner }
+    }
+}
+
+#[pymethods]
+impl PyCompaDecimal {
+    #[new]
+    #[pyo3(signature = (value = None))]
+    fn new(value: Option<Value>) -> PyResult<PyCompaDecimal> {
+        let inner = match value {
+            None => CompaDecimal::new(),
+            Some(Value::Compa(compa)) => compa.inner,
+            Some(Value::Int(num)) => from_int(num)?,
+            Some(Value::Str(value)) => value.parse().map_err(value_error)?,
+        };
+        Ok(inner.into())
+    }
+
+    #[staticmethod]
+    fn from_decimal_str(decimal: &str) -> PyResult<PyCompaDecimal> {
+        CompaDecimal::from_decimal_str(decimal)
+            .map(PyCompaDecimal::from)
+            .map_err(value_error)
+    }
+
+    #[getter]
+    fn value(&self) -> &str {
+        self.inner.get_value()
+    }
+
+    fn to_biguint(&self) -> PyResult<BigUint> {
+        self.inner.to_biguint().map_err(value_error)
+    }
+
+    fn __int__(&self) -> PyResult<BigUint> {
+        self.to_biguint()
+    }
+
+    fn __index__(&self) -> PyResult<BigUint> {
+        self.to_biguint()
+    }
+
+    fn __str__(&self) -> &str {
+        self.inner.get_value()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("CompaDecimal({:?})", self.inner.get_value())
+    }
+
+    fn __bool__(&self) -> bool {
+        self.inner.get_value().bytes().any(|digit| digit != b'0')
+    }
+
+    // Equality is digit-for-digit, like the Rust type, so "01" and "1" are different
+    // values here too and hashing the string keeps `__hash__` consistent with `__eq__`.
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.inner.get_value().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __richcmp__(&self, other: PyRef<'_, PyCompaDecimal>, op: CompareOp) -> bool {
+        op.matches(self.inner.cmp(&other.inner))
+    }
+
+    fn __add__(&self, other: Operand) -> PyResult<PyCompaDecimal> {
+        Ok((&self.inner + &other.into_compa()?).into())
+    }
+
+    fn __radd__(&self, other: Operand) -> PyResult<PyCompaDecimal> {
+        self.__add__(other)
+    }
+
+    fn __sub__(&self, other: Operand) -> PyResult<PyCompaDecimal> {
+        let other = other.into_compa()?;
+        self.inner
+            .subtract(other.get_value())
+            .map(PyCompaDecimal::from)
+            .map_err(value_error)
+    }
+
+    fn __rsub__(&self, other: Operand) -> PyResult<PyCompaDecimal> {
+        let other = other.into_compa()?;
+        other
+            .subtract(self.inner.get_value())
+            .map(PyCompaDecimal::from)
+            .map_err(value_error)
+    }
+
+    fn __mul__(&self, other: Operand) -> PyResult<PyCompaDecimal> {
+        Ok((&self.inner * &other.into_compa()?).into())
+    }
+
+    fn __rmul__(&self, other: Operand) -> PyResult<PyCompaDecimal> {
+        self.__mul__(other)
+    }
+
+    fn __floordiv__(&self, other: Operand) -> PyResult<PyCompaDecimal> {
+        let other = nonzero(other.into_compa()?)?;
+        Ok((&self.inner / &other).into())
+    }
+
+    fn __rfloordiv__(&self, other: Operand) -> PyResult<PyCompaDecimal> {
+        let divisor = nonzero(self.inner.clone())?;
+        Ok((&other.into_compa()? / &divisor).into())
+    }
+
+    fn __mod__(&self, other: Operand) -> PyResult<PyCompaDecimal> {
+        let other = nonzero(other.into_compa()?)?;
+        Ok((&self.inner % &other).into())
+    }
+
+    fn __rmod__(&self, other: Operand) -> PyResult<PyCompaDecimal> {
+        let divisor = nonzero(self.inner.clone())?;
+        Ok((&other.into_compa()? % &divisor).into())
+    }
+}
+
+#[pymodule]
+#[pyo3(name = "compa_decimal")]
+fn compa_decimal_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyCompaDecimal>()?;
+    Ok(())
+}
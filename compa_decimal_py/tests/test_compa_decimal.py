@@ -0,0 +1,98 @@
+import unittest
+
+from compa_decimal import CompaDecimal
+
+
+class ConversionTest(unittest.TestCase):
+    def test_from_int(self):
+        self.assertEqual(str(CompaDecimal(123456789)), "1T~PC")
+        self.assertEqual(CompaDecimal(255).value, "2$")
+        self.assertEqual(CompaDecimal().value, "0")
+
+    def test_to_int(self):
+        self.assertEqual(int(CompaDecimal("1T~PC")), 123456789)
+        self.assertEqual(CompaDecimal("2$").to_biguint(), 255)
+        self.assertEqual(hex(CompaDecimal("2$")), "0xff")
+
+    def test_arbitrary_precision(self):
+        big = 2**200 + 12345
+        self.assertEqual(int(CompaDecimal(big)), big)
+        self.assertEqual(int(CompaDecimal(2**128 - 1)), 2**128 - 1)
+
+    def test_from_decimal_str(self):
+        self.assertEqual(CompaDecimal.from_decimal_str("123456789").value, "1T~PC")
+        with self.assertRaises(ValueError):
+            CompaDecimal.from_decimal_str("12a")
+
+    def test_invalid_input(self):
+        with self.assertRaises(ValueError):
+            CompaDecimal("123asd£")
+        with self.assertRaises(ValueError):
+            CompaDecimal("")
+        with self.assertRaises(ValueError):
+            CompaDecimal(-1)
+        with self.assertRaises(TypeError):
+            CompaDecimal(1.5)
+
+    def test_repr_bool(self):
+        compa = CompaDecimal("1T~PC")
+        self.assertEqual(repr(compa), 'CompaDecimal("1T~PC")')
+        self.assertTrue(compa)
+        self.assertFalse(CompaDecimal("00"))
+
+
+class ComparisonTest(unittest.TestCase):
+    def test_ordering(self):
+        values = [CompaDecimal(n) for n in (123456789, 0, 255, 94, 95)]
+        self.assertEqual([int(v) for v in sorted(values)], [0, 94, 95, 255, 123456789])
+        self.assertLess(CompaDecimal("2$"), CompaDecimal("1T~PC"))
+        self.assertGreaterEqual(CompaDecimal("2$"), CompaDecimal("2$"))
+
+    def test_equality_is_digit_for_digit(self):
+        self.assertEqual(CompaDecimal("1T~PC"), CompaDecimal(123456789))
+        self.assertNotEqual(CompaDecimal("01"), CompaDecimal("1"))
+        self.assertNotEqual(CompaDecimal("1"), 1)
+
+    def test_hash(self):
+        self.assertEqual(hash(CompaDecimal("1T~PC")), hash(CompaDecimal(123456789)))
+        self.assertEqual(len({CompaDecimal(5), CompaDecimal("5"), CompaDecimal(6)}), 2)
+
+
+class ArithmeticTest(unittest.TestCase):
+    def test_operators(self):
+        a = CompaDecimal(123456789)
+        b = CompaDecimal(255)
+        self.assertEqual(int(a + b), 123456789 + 255)
+        self.assertEqual(int(a - b), 123456789 - 255)
+        self.assertEqual(int(a * b), 123456789 * 255)
+        self.assertEqual(int(a // b), 123456789 // 255)
+        self.assertEqual(int(a % b), 123456789 % 255)
+
+    def test_int_operands(self):
+        a = CompaDecimal(1000)
+        self.assertEqual(int(a + 1), 1001)
+        self.assertEqual(int(1 + a), 1001)
+        self.assertEqual(int(5000 - a), 4000)
+        self.assertEqual(int(3 * a), 3000)
+        self.assertEqual(int(7000 // a), 7)
+        self.assertEqual(int(1001 % a), 1)
+
+    def test_negative_result(self):
+        with self.assertRaises(ValueError):
+            CompaDecimal(1) - CompaDecimal(2)
+        with self.assertRaises(ValueError):
+            CompaDecimal(1) + -2
+
+    def test_division_by_zero(self):
+        with self.assertRaises(ZeroDivisionError):
+            CompaDecimal(1) // CompaDecimal("00")
+        with self.assertRaises(ZeroDivisionError):
+            5 % CompaDecimal(0)
+
+    def test_unsupported_operand(self):
+        with self.assertRaises(TypeError):
+            CompaDecimal(1) + "1"
+
+
+if __name__ == "__main__":
+    unittest.main()